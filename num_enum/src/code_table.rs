@@ -0,0 +1,86 @@
+//! Converting enums to and from codes in numbering schemes other than their discriminants.
+
+use ::core::fmt;
+use ::core::marker::PhantomData;
+
+/// Conversions between an enum and its codes in the code table identified by the marker type
+/// `Table`, as declared with `#[num_enum(code(..))]` on its variants.
+///
+/// This is implemented by [`CodeTables`](crate::CodeTables), which also generates the marker types,
+/// along with inherent `code` and `try_from_code` methods generic over the table.
+pub trait CodeTable<Table>: Sized {
+    type Primitive: Copy + Eq + fmt::Debug;
+
+    /// The name of the enum.
+    const NAME: &'static str;
+
+    /// The name of the code table, as written in `#[num_enum(code(..))]`.
+    const TABLE_NAME: &'static str;
+
+    /// Returns the code of this variant in the table.
+    fn code(&self) -> Self::Primitive;
+
+    /// Returns the variant whose code or alternative code in the table is `code`.
+    ///
+    /// Like `FromPrimitive`, unmatched codes are converted into the `fallback`, `default` or
+    /// `catch_all` variant if the enum has one, in which case this never fails.
+    fn try_from_code(code: Self::Primitive) -> Result<Self, TryFromCodeError<Self, Table>>;
+}
+
+/// The error returned when a code doesn't match any variant in a code table.
+pub struct TryFromCodeError<Enum: CodeTable<Table>, Table> {
+    pub code: Enum::Primitive,
+    _table: PhantomData<fn() -> Table>,
+}
+
+impl<Enum: CodeTable<Table>, Table> TryFromCodeError<Enum, Table> {
+    pub fn new(code: Enum::Primitive) -> Self {
+        Self {
+            code,
+            _table: PhantomData,
+        }
+    }
+}
+
+impl<Enum: CodeTable<Table>, Table> Clone for TryFromCodeError<Enum, Table> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Enum: CodeTable<Table>, Table> Copy for TryFromCodeError<Enum, Table> {}
+
+impl<Enum: CodeTable<Table>, Table> PartialEq for TryFromCodeError<Enum, Table> {
+    fn eq(&self, other: &Self) -> bool {
+        self.code == other.code
+    }
+}
+
+impl<Enum: CodeTable<Table>, Table> Eq for TryFromCodeError<Enum, Table> {}
+
+impl<Enum: CodeTable<Table>, Table> fmt::Debug for TryFromCodeError<Enum, Table> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct("TryFromCodeError")
+            .field("code", &self.code)
+            .finish()
+    }
+}
+
+impl<Enum: CodeTable<Table>, Table> fmt::Display for TryFromCodeError<Enum, Table> {
+    fn fmt(&self, stream: &'_ mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            stream,
+            "No variant of enum `{name}` has the code `{code:?}` in table `{table}`",
+            name = Enum::NAME,
+            code = self.code,
+            table = Enum::TABLE_NAME,
+        )
+    }
+}
+
+#[rustversion::since(1.81)]
+impl<Enum: CodeTable<Table>, Table> ::core::error::Error for TryFromCodeError<Enum, Table> {}
+
+#[cfg(feature = "std")]
+#[rustversion::before(1.81)]
+impl<Enum: CodeTable<Table>, Table> ::std::error::Error for TryFromCodeError<Enum, Table> {}
@@ -0,0 +1,241 @@
+//! A fixed-size map with one value per variant of an enum.
+
+use crate::Ordinal;
+use ::core::fmt;
+use ::core::hash::{Hash, Hasher};
+use ::core::iter::{Enumerate, FusedIterator};
+use ::core::marker::PhantomData;
+use ::core::ops::{Index, IndexMut};
+use ::core::slice;
+
+/// A map holding exactly one value for every variant of `E`.
+///
+/// Values are stored inline in an array indexed by each variant's [`Ordinal`], so lookups never
+/// fail, and sparse discriminants don't waste space.
+///
+/// ```rust
+/// use num_enum::{EnumMap, Ordinal};
+///
+/// #[derive(Clone, Copy, Debug, Eq, PartialEq, Ordinal)]
+/// #[repr(u8)]
+/// enum Opcode {
+///     Nop = 0x10,
+///     Load = 0x20,
+///     Store = 0x80,
+/// }
+///
+/// let mut counts: EnumMap<Opcode, u32> = EnumMap::default();
+/// counts[Opcode::Load] += 2;
+/// counts[Opcode::Store] += 1;
+///
+/// assert_eq!(counts[Opcode::Nop], 0);
+/// assert_eq!(
+///     counts.iter().map(|(opcode, count)| (opcode, *count)).collect::<Vec<_>>(),
+///     vec![(Opcode::Nop, 0), (Opcode::Load, 2), (Opcode::Store, 1)],
+/// );
+/// ```
+pub struct EnumMap<E: Ordinal, V> {
+    values: E::Array<V>,
+}
+
+impl<E: Ordinal, V> EnumMap<E, V> {
+    /// Creates a map by calling `f` once for each variant, in declaration order.
+    pub fn from_fn<F: FnMut(E) -> V>(mut f: F) -> Self {
+        Self {
+            values: E::array_from_fn(|ordinal| f(variant_at(ordinal))),
+        }
+    }
+
+    /// Returns the number of entries, which is always `E::VARIANT_COUNT`.
+    pub fn len(&self) -> usize {
+        E::VARIANT_COUNT
+    }
+
+    /// Returns whether `E` has no variants.
+    pub fn is_empty(&self) -> bool {
+        E::VARIANT_COUNT == 0
+    }
+
+    /// Returns the value for `key`.
+    pub fn get(&self, key: E) -> &V {
+        &self.values.as_ref()[key.ordinal()]
+    }
+
+    /// Returns the value for `key` mutably.
+    pub fn get_mut(&mut self, key: E) -> &mut V {
+        &mut self.values.as_mut()[key.ordinal()]
+    }
+
+    /// Returns the values in declaration order of their variants.
+    pub fn as_slice(&self) -> &[V] {
+        self.values.as_ref()
+    }
+
+    /// Returns the values mutably, in declaration order of their variants.
+    pub fn as_mut_slice(&mut self) -> &mut [V] {
+        self.values.as_mut()
+    }
+
+    /// Iterates over the values in declaration order of their variants.
+    pub fn values(&self) -> slice::Iter<'_, V> {
+        self.as_slice().iter()
+    }
+
+    /// Iterates mutably over the values in declaration order of their variants.
+    pub fn values_mut(&mut self) -> slice::IterMut<'_, V> {
+        self.as_mut_slice().iter_mut()
+    }
+
+    /// Iterates over `(variant, &value)` pairs in declaration order.
+    pub fn iter(&self) -> Iter<'_, E, V> {
+        Iter {
+            inner: self.values().enumerate(),
+            _enum: PhantomData,
+        }
+    }
+
+    /// Iterates over `(variant, &mut value)` pairs in declaration order.
+    pub fn iter_mut(&mut self) -> IterMut<'_, E, V> {
+        IterMut {
+            inner: self.values_mut().enumerate(),
+            _enum: PhantomData,
+        }
+    }
+}
+
+fn variant_at<E: Ordinal>(ordinal: usize) -> E {
+    E::from_ordinal(ordinal).expect("Ordinal implementation doesn't cover VARIANT_COUNT variants")
+}
+
+impl<E: Ordinal, V> Index<E> for EnumMap<E, V> {
+    type Output = V;
+
+    fn index(&self, key: E) -> &V {
+        self.get(key)
+    }
+}
+
+impl<E: Ordinal, V> IndexMut<E> for EnumMap<E, V> {
+    fn index_mut(&mut self, key: E) -> &mut V {
+        self.get_mut(key)
+    }
+}
+
+impl<E: Ordinal, V: Default> Default for EnumMap<E, V> {
+    fn default() -> Self {
+        Self {
+            values: E::array_from_fn(|_| V::default()),
+        }
+    }
+}
+
+impl<E: Ordinal, V: Clone> Clone for EnumMap<E, V> {
+    fn clone(&self) -> Self {
+        let values = self.as_slice();
+        Self {
+            values: E::array_from_fn(|ordinal| values[ordinal].clone()),
+        }
+    }
+}
+
+impl<E: Ordinal + fmt::Debug, V: fmt::Debug> fmt::Debug for EnumMap<E, V> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<E: Ordinal, V: PartialEq> PartialEq for EnumMap<E, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<E: Ordinal, V: Eq> Eq for EnumMap<E, V> {}
+
+impl<E: Ordinal, V: Hash> Hash for EnumMap<E, V> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state)
+    }
+}
+
+impl<'a, E: Ordinal, V> IntoIterator for &'a EnumMap<E, V> {
+    type Item = (E, &'a V);
+    type IntoIter = Iter<'a, E, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, E: Ordinal, V> IntoIterator for &'a mut EnumMap<E, V> {
+    type Item = (E, &'a mut V);
+    type IntoIter = IterMut<'a, E, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+/// An iterator over the `(variant, &value)` pairs of an [`EnumMap`].
+pub struct Iter<'a, E, V> {
+    inner: Enumerate<slice::Iter<'a, V>>,
+    _enum: PhantomData<fn() -> E>,
+}
+
+impl<'a, E: Ordinal, V> Iterator for Iter<'a, E, V> {
+    type Item = (E, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner
+            .next()
+            .map(|(ordinal, value)| (variant_at(ordinal), value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<E: Ordinal, V> DoubleEndedIterator for Iter<'_, E, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner
+            .next_back()
+            .map(|(ordinal, value)| (variant_at(ordinal), value))
+    }
+}
+
+impl<E: Ordinal, V> ExactSizeIterator for Iter<'_, E, V> {}
+
+impl<E: Ordinal, V> FusedIterator for Iter<'_, E, V> {}
+
+/// An iterator over the `(variant, &mut value)` pairs of an [`EnumMap`].
+pub struct IterMut<'a, E, V> {
+    inner: Enumerate<slice::IterMut<'a, V>>,
+    _enum: PhantomData<fn() -> E>,
+}
+
+impl<'a, E: Ordinal, V> Iterator for IterMut<'a, E, V> {
+    type Item = (E, &'a mut V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner
+            .next()
+            .map(|(ordinal, value)| (variant_at(ordinal), value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<E: Ordinal, V> DoubleEndedIterator for IterMut<'_, E, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner
+            .next_back()
+            .map(|(ordinal, value)| (variant_at(ordinal), value))
+    }
+}
+
+impl<E: Ordinal, V> ExactSizeIterator for IterMut<'_, E, V> {}
+
+impl<E: Ordinal, V> FusedIterator for IterMut<'_, E, V> {}
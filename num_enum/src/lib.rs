@@ -8,15 +8,48 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 pub use ::num_enum_derive::{
-    Default, FromPrimitive, IntoPrimitive, TryFromPrimitive, UnsafeFromPrimitive,
+    CodeTables, Default, EnumMetadata, FromPrimitive, IntoPrimitive, Ordinal, Subsets,
+    TaggedPayload, TryFromPrimitive, UnsafeFromPrimitive, ValueConstants,
 };
 
 use ::core::fmt;
 
+pub mod code_table;
+pub use code_table::{CodeTable, TryFromCodeError};
+
+pub mod enum_map;
+pub use enum_map::EnumMap;
+
+pub mod maybe_known;
+pub use maybe_known::MaybeKnown;
+
+pub mod payload;
+pub use payload::{DecodePayload, EncodePayload, PayloadError, TaggedPayloadError};
+
+// Used by code generated for `#[num_enum(num_traits)]`, so that dependees don't need to depend on
+// num-traits under a particular name themselves.
+#[cfg(feature = "num-traits")]
+#[doc(hidden)]
+pub use ::num_traits;
+
+// Used by code generated for `#[num_enum(deprecated_value_warning)]`, so that dependees don't need
+// to depend on log under a particular name themselves.
+#[cfg(feature = "log")]
+#[doc(hidden)]
+pub use ::log;
+
 pub trait FromPrimitive: Sized {
     type Primitive: Copy + Eq;
 
     fn from_primitive(number: Self::Primitive) -> Self;
+
+    /// Converts `number` like `from_primitive`, also returning whether it didn't match any variant,
+    /// so fell back to the `default` or `catch_all` variant.
+    ///
+    /// Implementations which don't override this always report `false`.
+    fn from_primitive_reporting(number: Self::Primitive) -> (Self, bool) {
+        (Self::from_primitive(number), false)
+    }
 }
 
 pub trait TryFromPrimitive: Sized {
@@ -52,6 +85,58 @@ pub trait UnsafeFromPrimitive: Sized {
     unsafe fn unchecked_transmute_from(number: Self::Primitive) -> Self;
 }
 
+/// Compile-time information about the discriminants of an enum.
+///
+/// Only canonical discriminants are considered for the bounds: alternatives and catch-all
+/// variants are ignored.
+pub trait EnumMetadata {
+    type Primitive: Copy + Eq;
+
+    /// The smallest canonical discriminant of any variant.
+    const MIN_DISCRIMINANT: Self::Primitive;
+
+    /// The largest canonical discriminant of any variant.
+    const MAX_DISCRIMINANT: Self::Primitive;
+
+    /// Whether every value of `Self::Primitive` is the discriminant or an alternative of some
+    /// variant which isn't `#[num_enum(skip)]`, without needing a default or catch-all variant.
+    const IS_EXHAUSTIVE: bool;
+
+    /// Whether a variant is marked `#[default]` or `#[num_enum(default)]`.
+    const HAS_DEFAULT: bool;
+
+    /// Whether a variant is marked `#[num_enum(fallback)]`.
+    const HAS_FALLBACK: bool;
+
+    /// Whether a variant is marked `#[num_enum(catch_all)]`.
+    const HAS_CATCH_ALL: bool;
+
+    /// Whether the canonical discriminants cover every value between `MIN_DISCRIMINANT` and
+    /// `MAX_DISCRIMINANT` inclusive.
+    const IS_CONTIGUOUS: bool;
+}
+
+/// A dense, zero-based index for each variant of an enum, in declaration order.
+///
+/// Unlike discriminants, ordinals are always `0..VARIANT_COUNT`, so they can be used to index
+/// per-variant arrays.
+pub trait Ordinal: Sized {
+    /// The number of variants in the enum.
+    const VARIANT_COUNT: usize;
+
+    /// Returns the position of this variant in declaration order.
+    fn ordinal(self) -> usize;
+
+    /// Returns the variant at position `ordinal` in declaration order, if there is one.
+    fn from_ordinal(ordinal: usize) -> Option<Self>;
+
+    /// `[V; Self::VARIANT_COUNT]`, which can't be spelled generically on stable Rust.
+    type Array<V>: AsRef<[V]> + AsMut<[V]>;
+
+    /// Builds an array with one element per variant, by calling `f` with each ordinal in order.
+    fn array_from_fn<V, F: FnMut(usize) -> V>(f: F) -> Self::Array<V>;
+}
+
 #[derive(Copy, Clone, PartialEq, Eq)]
 pub struct TryFromPrimitiveError<Enum: TryFromPrimitive> {
     pub number: Enum::Primitive,
@@ -88,6 +173,101 @@ impl<Enum: TryFromPrimitive> ::core::error::Error for TryFromPrimitiveError<Enum
 #[rustversion::before(1.81)]
 impl<Enum: TryFromPrimitive> ::std::error::Error for TryFromPrimitiveError<Enum> {}
 
+/// The error returned by the `try_from_primitive_versioned` method which `TryFromPrimitive`
+/// generates for enums with `#[num_enum(since = ..)]` or `#[num_enum(until = ..)]` variants.
+pub enum TryFromPrimitiveVersionedError<Enum: TryFromPrimitive> {
+    /// The number didn't match any variant, in any version.
+    Unknown(Enum::Error),
+    /// The number matched a variant, but that variant isn't valid in the requested version.
+    NotInVersion {
+        number: Enum::Primitive,
+        version: u32,
+    },
+}
+
+impl<Enum: TryFromPrimitive> Clone for TryFromPrimitiveVersionedError<Enum>
+where
+    Enum::Error: Clone,
+{
+    fn clone(&self) -> Self {
+        match self {
+            Self::Unknown(err) => Self::Unknown(err.clone()),
+            Self::NotInVersion { number, version } => Self::NotInVersion {
+                number: *number,
+                version: *version,
+            },
+        }
+    }
+}
+
+impl<Enum: TryFromPrimitive> Copy for TryFromPrimitiveVersionedError<Enum> where Enum::Error: Copy {}
+
+impl<Enum: TryFromPrimitive> PartialEq for TryFromPrimitiveVersionedError<Enum>
+where
+    Enum::Error: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Unknown(err), Self::Unknown(other_err)) => err == other_err,
+            (
+                Self::NotInVersion { number, version },
+                Self::NotInVersion {
+                    number: other_number,
+                    version: other_version,
+                },
+            ) => number == other_number && version == other_version,
+            _ => false,
+        }
+    }
+}
+
+impl<Enum: TryFromPrimitive> Eq for TryFromPrimitiveVersionedError<Enum> where Enum::Error: Eq {}
+
+impl<Enum: TryFromPrimitive> fmt::Debug for TryFromPrimitiveVersionedError<Enum>
+where
+    Enum::Error: fmt::Debug,
+{
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Unknown(err) => fmt.debug_tuple("Unknown").field(err).finish(),
+            Self::NotInVersion { number, version } => fmt
+                .debug_struct("NotInVersion")
+                .field("number", number)
+                .field("version", version)
+                .finish(),
+        }
+    }
+}
+
+impl<Enum: TryFromPrimitive> fmt::Display for TryFromPrimitiveVersionedError<Enum>
+where
+    Enum::Error: fmt::Display,
+{
+    fn fmt(&self, stream: &'_ mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unknown(err) => fmt::Display::fmt(err, stream),
+            Self::NotInVersion { number, version } => write!(
+                stream,
+                "The value `{number:?}` of enum `{name}` isn't valid in version {version}",
+                name = Enum::NAME,
+            ),
+        }
+    }
+}
+
+#[rustversion::since(1.81)]
+impl<Enum: TryFromPrimitive> ::core::error::Error for TryFromPrimitiveVersionedError<Enum> where
+    Enum::Error: fmt::Debug + fmt::Display
+{
+}
+
+#[cfg(feature = "std")]
+#[rustversion::before(1.81)]
+impl<Enum: TryFromPrimitive> ::std::error::Error for TryFromPrimitiveVersionedError<Enum> where
+    Enum::Error: fmt::Debug + fmt::Display
+{
+}
+
 // This trait exists to try to give a more clear error message when someone attempts to derive both FromPrimitive and TryFromPrimitive.
 // This isn't allowed because both end up creating a `TryFrom<primitive>` implementation.
 // TryFromPrimitive explicitly implements TryFrom<primitive> with Error=TryFromPrimitiveError, which conflicts with:
@@ -97,3 +277,25 @@ impl<Enum: TryFromPrimitive> ::std::error::Error for TryFromPrimitiveError<Enum>
 // It is subject to change in any release regardless of semver.
 #[doc(hidden)]
 pub trait CannotDeriveBothFromPrimitiveAndTryFromPrimitive {}
+
+// This trait exists so that derives other than ValueConstants can give a compile error when `#[num_enum(value_module = ..)]`
+// is used on an enum which doesn't derive ValueConstants, rather than silently ignoring the attribute.
+//
+// This is a private implementation detail of the num_enum crate which should not be depended on externally.
+// It is subject to change in any release regardless of semver.
+#[doc(hidden)]
+pub trait HasValueConstants {}
+
+// Likewise for `#[num_enum(code(..))]` on the variants of an enum which doesn't derive CodeTables.
+//
+// This is a private implementation detail of the num_enum crate which should not be depended on externally.
+// It is subject to change in any release regardless of semver.
+#[doc(hidden)]
+pub trait HasCodeTables {}
+
+// Likewise for `#[num_enum(subset(..))]` on an enum which doesn't derive Subsets.
+//
+// This is a private implementation detail of the num_enum crate which should not be depended on externally.
+// It is subject to change in any release regardless of semver.
+#[doc(hidden)]
+pub trait HasSubsets {}
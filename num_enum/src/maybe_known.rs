@@ -0,0 +1,121 @@
+//! A primitive which may or may not be the discriminant of a known variant of an enum.
+
+use crate::{TryFromPrimitive, TryFromPrimitiveError};
+use ::core::cmp::Ordering;
+use ::core::fmt;
+use ::core::hash::{Hash, Hasher};
+
+/// A raw primitive value which is expected to be the discriminant of a variant of `E`, but may not
+/// be, for instance because it came from a newer version of a wire format or C library.
+///
+/// It has the same layout as `E::Primitive`, so can be used as a field of `#[repr(C)]` structs, and
+/// unknown values are preserved rather than being rejected or mapped to a default. Comparisons and
+/// hashing use the raw value.
+///
+/// ```rust
+/// use num_enum::{IntoPrimitive, MaybeKnown, TryFromPrimitive};
+///
+/// #[derive(Debug, Eq, PartialEq, IntoPrimitive, TryFromPrimitive)]
+/// #[repr(u8)]
+/// enum Colour {
+///     Red,
+///     Green,
+/// }
+///
+/// #[repr(C)]
+/// struct Pixel {
+///     colour: MaybeKnown<Colour>,
+///     intensity: u8,
+/// }
+///
+/// let pixel = Pixel { colour: MaybeKnown::from_raw(7), intensity: 255 };
+/// assert!(pixel.colour.known().is_err());
+/// assert_eq!(pixel.colour.raw(), 7);
+/// assert_eq!(format!("{:?}", pixel.colour), "Unknown(7)");
+///
+/// let green = MaybeKnown::from(Colour::Green);
+/// assert_eq!(green.known(), Ok(Colour::Green));
+/// assert_eq!(format!("{:?}", green), "Green");
+/// ```
+#[repr(transparent)]
+pub struct MaybeKnown<E: TryFromPrimitive>(E::Primitive);
+
+impl<E: TryFromPrimitive> MaybeKnown<E> {
+    /// Wraps a raw value, which need not be the discriminant of any variant of `E`.
+    pub const fn from_raw(raw: E::Primitive) -> Self {
+        Self(raw)
+    }
+
+    /// Returns the raw value.
+    pub const fn raw(self) -> E::Primitive {
+        self.0
+    }
+
+    /// Returns the variant the raw value corresponds to, or an error if there isn't one.
+    pub fn known(self) -> Result<E, TryFromPrimitiveError<E>> {
+        E::try_from_primitive(self.0).map_err(|_| TryFromPrimitiveError::new(self.0))
+    }
+
+    /// Returns whether the raw value corresponds to a variant.
+    pub fn is_known(self) -> bool {
+        self.known().is_ok()
+    }
+}
+
+impl<E: TryFromPrimitive + Into<E::Primitive>> From<E> for MaybeKnown<E> {
+    fn from(value: E) -> Self {
+        Self(value.into())
+    }
+}
+
+impl<E: TryFromPrimitive> Clone for MaybeKnown<E> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<E: TryFromPrimitive> Copy for MaybeKnown<E> {}
+
+impl<E: TryFromPrimitive + fmt::Debug> fmt::Debug for MaybeKnown<E> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match self.known() {
+            Ok(known) => fmt::Debug::fmt(&known, fmt),
+            Err(_) => fmt.debug_tuple("Unknown").field(&self.0).finish(),
+        }
+    }
+}
+
+impl<E: TryFromPrimitive> PartialEq for MaybeKnown<E> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<E: TryFromPrimitive> Eq for MaybeKnown<E> {}
+
+impl<E: TryFromPrimitive> Hash for MaybeKnown<E>
+where
+    E::Primitive: Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state)
+    }
+}
+
+impl<E: TryFromPrimitive> PartialOrd for MaybeKnown<E>
+where
+    E::Primitive: Ord,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<E: TryFromPrimitive> Ord for MaybeKnown<E>
+where
+    E::Primitive: Ord,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp(&other.0)
+    }
+}
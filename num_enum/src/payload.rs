@@ -0,0 +1,160 @@
+//! Encoding and decoding the fields of enums deriving [`TaggedPayload`](crate::TaggedPayload).
+//!
+//! All integers are encoded in little-endian byte order, with no padding between fields.
+
+use crate::{TryFromPrimitive, TryFromPrimitiveError};
+use ::core::fmt;
+
+/// A field type which can be decoded from the payload of a tagged enum.
+pub trait DecodePayload: Sized {
+    /// Decodes a value from the start of `payload`, returning it along with the unused remainder
+    /// of `payload`.
+    fn decode_payload(payload: &[u8]) -> Result<(Self, &[u8]), PayloadError>;
+}
+
+/// A field type which can be encoded into the payload of a tagged enum.
+pub trait EncodePayload {
+    /// Encodes the value into the start of `buf`, returning the number of bytes written.
+    fn encode_payload(&self, buf: &mut [u8]) -> Result<usize, PayloadError>;
+}
+
+/// An error encountered when encoding or decoding a payload.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PayloadError {
+    /// The payload ended before all of the fields were decoded.
+    UnexpectedEnd,
+    /// The payload had bytes left over after all of the fields were decoded.
+    TrailingBytes,
+    /// The buffer being encoded into was too small to hold all of the fields.
+    BufferTooSmall,
+    /// The bytes of a field weren't a valid value of its type.
+    InvalidValue,
+}
+
+impl fmt::Display for PayloadError {
+    fn fmt(&self, stream: &'_ mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            PayloadError::UnexpectedEnd => "Payload ended unexpectedly",
+            PayloadError::TrailingBytes => "Payload had unexpected trailing bytes",
+            PayloadError::BufferTooSmall => "Buffer is too small for payload",
+            PayloadError::InvalidValue => "Payload contained an invalid value",
+        };
+        stream.write_str(message)
+    }
+}
+
+#[rustversion::since(1.81)]
+impl ::core::error::Error for PayloadError {}
+
+#[cfg(feature = "std")]
+#[rustversion::before(1.81)]
+impl ::std::error::Error for PayloadError {}
+
+/// The error returned when converting a `(tag, payload)` pair into an enum deriving
+/// [`TaggedPayload`](crate::TaggedPayload) fails.
+///
+/// `Tag` is the companion enum of tags generated by the derive.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum TaggedPayloadError<Tag: TryFromPrimitive> {
+    /// The tag didn't match any variant.
+    UnknownTag(TryFromPrimitiveError<Tag>),
+    /// The tag matched a variant, but its fields couldn't be decoded from the payload.
+    Payload(PayloadError),
+}
+
+impl<Tag: TryFromPrimitive> fmt::Debug for TaggedPayloadError<Tag> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TaggedPayloadError::UnknownTag(err) => {
+                fmt.debug_tuple("UnknownTag").field(err).finish()
+            }
+            TaggedPayloadError::Payload(err) => fmt.debug_tuple("Payload").field(err).finish(),
+        }
+    }
+}
+
+impl<Tag: TryFromPrimitive> fmt::Display for TaggedPayloadError<Tag> {
+    fn fmt(&self, stream: &'_ mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TaggedPayloadError::UnknownTag(err) => fmt::Display::fmt(err, stream),
+            TaggedPayloadError::Payload(err) => fmt::Display::fmt(err, stream),
+        }
+    }
+}
+
+#[rustversion::since(1.81)]
+impl<Tag: TryFromPrimitive> ::core::error::Error for TaggedPayloadError<Tag> {}
+
+#[cfg(feature = "std")]
+#[rustversion::before(1.81)]
+impl<Tag: TryFromPrimitive> ::std::error::Error for TaggedPayloadError<Tag> {}
+
+fn split_payload<const N: usize>(payload: &[u8]) -> Result<([u8; N], &[u8]), PayloadError> {
+    if payload.len() < N {
+        return Err(PayloadError::UnexpectedEnd);
+    }
+    let (bytes, rest) = payload.split_at(N);
+    let mut array = [0; N];
+    array.copy_from_slice(bytes);
+    Ok((array, rest))
+}
+
+fn write_payload(bytes: &[u8], buf: &mut [u8]) -> Result<usize, PayloadError> {
+    match buf.get_mut(..bytes.len()) {
+        Some(dest) => {
+            dest.copy_from_slice(bytes);
+            Ok(bytes.len())
+        }
+        None => Err(PayloadError::BufferTooSmall),
+    }
+}
+
+macro_rules! impl_payload_for_integers {
+    ($($ty:ty),*) => {
+        $(
+            impl DecodePayload for $ty {
+                fn decode_payload(payload: &[u8]) -> Result<(Self, &[u8]), PayloadError> {
+                    let (bytes, rest) = split_payload(payload)?;
+                    Ok((<$ty>::from_le_bytes(bytes), rest))
+                }
+            }
+
+            impl EncodePayload for $ty {
+                fn encode_payload(&self, buf: &mut [u8]) -> Result<usize, PayloadError> {
+                    write_payload(&self.to_le_bytes(), buf)
+                }
+            }
+        )*
+    };
+}
+
+// usize and isize are deliberately excluded, as their encoded size would depend on the target.
+impl_payload_for_integers!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+impl DecodePayload for bool {
+    fn decode_payload(payload: &[u8]) -> Result<(Self, &[u8]), PayloadError> {
+        match u8::decode_payload(payload)? {
+            (0, rest) => Ok((false, rest)),
+            (1, rest) => Ok((true, rest)),
+            _ => Err(PayloadError::InvalidValue),
+        }
+    }
+}
+
+impl EncodePayload for bool {
+    fn encode_payload(&self, buf: &mut [u8]) -> Result<usize, PayloadError> {
+        u8::from(*self).encode_payload(buf)
+    }
+}
+
+impl<const N: usize> DecodePayload for [u8; N] {
+    fn decode_payload(payload: &[u8]) -> Result<(Self, &[u8]), PayloadError> {
+        split_payload(payload)
+    }
+}
+
+impl<const N: usize> EncodePayload for [u8; N] {
+    fn encode_payload(&self, buf: &mut [u8]) -> Result<usize, PayloadError> {
+        write_payload(self, buf)
+    }
+}
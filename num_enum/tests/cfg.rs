@@ -0,0 +1,208 @@
+// `cfg(not(any()))` is always enabled, and `cfg(any())` is always disabled.
+//
+// rustc evaluates `#[cfg]`s on variants before invoking derives, so these check that the generated
+// code follows whichever variants are enabled.
+
+use ::num_enum::{
+    EnumMetadata, FromPrimitive, IntoPrimitive, Ordinal, TaggedPayload, TryFromPrimitive,
+    UnsafeFromPrimitive,
+};
+use ::std::convert::TryFrom;
+
+// Guard against https://github.com/illicitonion/num_enum/issues/27
+mod alloc {}
+mod core {}
+mod num_enum {}
+mod std {}
+
+#[test]
+fn try_from_primitive() {
+    #[derive(Debug, Eq, PartialEq, IntoPrimitive, TryFromPrimitive)]
+    #[repr(u8)]
+    enum Enum {
+        Zero,
+        #[cfg(any())]
+        Gone,
+        #[cfg(not(any()))]
+        Present,
+        Two,
+    }
+
+    assert_eq!(Enum::try_from(0), Ok(Enum::Zero));
+    assert_eq!(Enum::try_from(1), Ok(Enum::Present));
+    assert_eq!(Enum::try_from(2), Ok(Enum::Two));
+    assert!(Enum::try_from(3).is_err());
+    assert_eq!(u8::from(Enum::Two), 2);
+}
+
+#[test]
+fn implicit_discriminants_skip_disabled_variants() {
+    const ONE: u8 = 1;
+
+    #[derive(Debug, Eq, PartialEq, TryFromPrimitive)]
+    #[repr(u8)]
+    enum Enum {
+        One = ONE,
+        #[cfg(any())]
+        Gone = 10,
+        Two,
+        #[cfg(any())]
+        AlsoGone,
+        Three,
+    }
+
+    assert_eq!(Enum::Two as u8, 2);
+    assert_eq!(Enum::try_from(2), Ok(Enum::Two));
+    assert_eq!(Enum::try_from(3), Ok(Enum::Three));
+    assert!(Enum::try_from(10).is_err());
+}
+
+#[test]
+fn mutually_exclusive_discriminants() {
+    #[derive(Debug, Eq, PartialEq, TryFromPrimitive)]
+    #[repr(u8)]
+    enum Enum {
+        Zero = 0,
+        #[cfg(not(any()))]
+        New = 1,
+        #[cfg(any())]
+        Old = 1,
+        #[cfg_attr(all(), cfg(any()))]
+        AlsoOld = 2,
+        #[cfg_attr(any(), cfg(any()))]
+        NotOld = 3,
+    }
+
+    assert_eq!(Enum::try_from(1), Ok(Enum::New));
+    assert!(Enum::try_from(2).is_err());
+    assert_eq!(Enum::try_from(3), Ok(Enum::NotOld));
+}
+
+#[test]
+fn from_primitive_falls_back_to_default() {
+    #[derive(Debug, Eq, PartialEq, FromPrimitive)]
+    #[repr(u8)]
+    enum Enum {
+        Zero,
+        #[cfg(any())]
+        Gone,
+        #[num_enum(default)]
+        Other = 10,
+    }
+
+    assert_eq!(Enum::from(0), Enum::Zero);
+    assert_eq!(Enum::from(1), Enum::Other);
+}
+
+#[test]
+fn enabled_default_and_catch_all() {
+    #[derive(Debug, Eq, PartialEq, FromPrimitive)]
+    #[repr(u8)]
+    enum WithDefault {
+        Zero,
+        #[cfg(not(any()))]
+        #[num_enum(default)]
+        Other,
+    }
+
+    #[derive(Debug, Eq, PartialEq, FromPrimitive)]
+    #[repr(u8)]
+    enum WithCatchAll {
+        Zero,
+        #[cfg_attr(all(), num_enum(catch_all))]
+        Other(u8),
+    }
+
+    assert_eq!(WithDefault::from(0), WithDefault::Zero);
+    assert_eq!(WithDefault::from(5), WithDefault::Other);
+    assert_eq!(WithCatchAll::from(5), WithCatchAll::Other(5));
+}
+
+#[test]
+fn catch_all() {
+    #[derive(Debug, Eq, PartialEq, FromPrimitive, IntoPrimitive, UnsafeFromPrimitive)]
+    #[repr(u8)]
+    enum Enum {
+        Zero,
+        #[cfg(any())]
+        Gone,
+        #[cfg(not(any()))]
+        #[num_enum(alternatives = [5])]
+        One,
+        #[num_enum(catch_all)]
+        Other(u8),
+    }
+
+    assert_eq!(Enum::from(1), Enum::One);
+    assert_eq!(Enum::from(5), Enum::One);
+    assert_eq!(Enum::from(2), Enum::Other(2));
+    assert_eq!(u8::from(Enum::One), 1);
+    assert_eq!(u8::from(Enum::Other(7)), 7);
+    assert_eq!(unsafe { Enum::unchecked_transmute_from(5) }, Enum::One);
+}
+
+#[test]
+fn ordinal() {
+    #[derive(Debug, Eq, PartialEq, Ordinal)]
+    #[repr(u8)]
+    enum Enum {
+        #[cfg(any())]
+        Gone,
+        A,
+        #[cfg(not(any()))]
+        B,
+        #[cfg(any())]
+        AlsoGone,
+        C,
+        #[cfg(any())]
+        StillGone,
+    }
+
+    assert_eq!(Enum::VARIANT_COUNT, 3);
+    assert_eq!(Enum::A.ordinal(), 0);
+    assert_eq!(Enum::B.ordinal(), 1);
+    assert_eq!(Enum::C.ordinal(), 2);
+    assert_eq!(Enum::from_ordinal(2), Some(Enum::C));
+    assert_eq!(Enum::from_ordinal(3), None);
+    assert_eq!(Enum::C.wrapping_next(), Enum::A);
+    assert_eq!(Enum::A.wrapping_prev(), Enum::C);
+    assert_eq!(Enum::C.saturating_next(), Enum::C);
+    assert_eq!(Enum::A.saturating_prev(), Enum::A);
+}
+
+#[test]
+fn enum_metadata() {
+    #[derive(EnumMetadata)]
+    #[repr(i8)]
+    #[allow(dead_code)]
+    enum Enum {
+        #[cfg(any())]
+        Low = -100,
+        Zero = 0,
+        One,
+        #[cfg(any())]
+        High = 100,
+    }
+
+    assert_eq!(Enum::MIN_DISCRIMINANT, 0);
+    assert_eq!(Enum::MAX_DISCRIMINANT, 1);
+    assert_eq!((Enum::IS_CONTIGUOUS, Enum::IS_EXHAUSTIVE), (true, false));
+}
+
+#[test]
+fn tagged_payload() {
+    #[derive(Debug, Eq, PartialEq, TaggedPayload)]
+    #[repr(u8)]
+    enum Message {
+        Ping(u8),
+        #[cfg(any())]
+        Gone(u16),
+        Pong(u8),
+    }
+
+    assert_eq!(Message::try_from((1, &[3][..])), Ok(Message::Pong(3)));
+    assert_eq!(Message::Pong(3).tag(), MessageTag::Pong);
+
+    let mut buf = [0; 1];
+    assert_eq!(Message::Pong(3).encode_tagged(&mut buf), Ok((1, 1)));
+}
@@ -0,0 +1,115 @@
+use ::num_enum::CodeTable;
+
+// Guard against https://github.com/illicitonion/num_enum/issues/27
+mod alloc {}
+mod core {}
+mod num_enum {}
+mod std {}
+
+const DYNAMIC: u8 = 96;
+
+#[derive(Debug, Eq, PartialEq, ::num_enum::CodeTables)]
+#[repr(u8)]
+enum Codec {
+    #[num_enum(code(rtp = 0, mp4 = 0x6D))]
+    Mulaw = 1,
+    #[num_enum(code(rtp = DYNAMIC, mp4 = [0x61, 0x62]))]
+    Aac = 2,
+    #[num_enum(code(rtp = DYNAMIC + 1, mp4 = 0x6F))]
+    Opus = 3,
+}
+
+#[test]
+fn codes() {
+    assert_eq!(Codec::Mulaw.code::<CodecRtp>(), 0);
+    assert_eq!(Codec::Aac.code::<CodecRtp>(), 96);
+    assert_eq!(Codec::Opus.code::<CodecRtp>(), 97);
+    assert_eq!(Codec::Mulaw.code::<CodecMp4>(), 0x6D);
+    // Alternatives are only used for decoding.
+    assert_eq!(Codec::Aac.code::<CodecMp4>(), 0x61);
+}
+
+#[test]
+fn try_from_code() {
+    assert_eq!(Codec::try_from_code::<CodecRtp>(0), Ok(Codec::Mulaw));
+    assert_eq!(Codec::try_from_code::<CodecRtp>(97), Ok(Codec::Opus));
+    assert_eq!(Codec::try_from_code::<CodecMp4>(0x61), Ok(Codec::Aac));
+    assert_eq!(Codec::try_from_code::<CodecMp4>(0x62), Ok(Codec::Aac));
+
+    let err = Codec::try_from_code::<CodecMp4>(0).unwrap_err();
+    assert_eq!(err.code, 0);
+    assert_eq!(
+        err.to_string(),
+        "No variant of enum `Codec` has the code `0` in table `mp4`"
+    );
+}
+
+#[test]
+fn generic_over_tables() {
+    fn round_trip<Table>(codec: Codec) -> Codec
+    where
+        Codec: CodeTable<Table>,
+    {
+        Codec::try_from_code::<Table>(codec.code::<Table>()).unwrap()
+    }
+
+    assert_eq!(round_trip::<CodecRtp>(Codec::Opus), Codec::Opus);
+    assert_eq!(round_trip::<CodecMp4>(Codec::Aac), Codec::Aac);
+    assert_eq!(<Codec as CodeTable<CodecMp4>>::TABLE_NAME, "mp4");
+}
+
+#[test]
+fn default_and_skip() {
+    #[derive(Debug, Eq, PartialEq, ::num_enum::CodeTables)]
+    #[repr(u8)]
+    enum Enum {
+        #[num_enum(code(legacy = 10), skip)]
+        Invalid,
+        #[num_enum(code(legacy = 11))]
+        One,
+        #[num_enum(default, code(legacy = 12))]
+        Other,
+    }
+
+    assert_eq!(Enum::Invalid.code::<EnumLegacy>(), 10);
+    assert_eq!(Enum::try_from_code::<EnumLegacy>(10), Ok(Enum::Other));
+    assert_eq!(Enum::try_from_code::<EnumLegacy>(11), Ok(Enum::One));
+    assert_eq!(Enum::try_from_code::<EnumLegacy>(200), Ok(Enum::Other));
+}
+
+#[test]
+fn catch_all() {
+    #[derive(Debug, Eq, PartialEq, ::num_enum::CodeTables)]
+    #[repr(u16)]
+    enum Enum {
+        #[num_enum(code(wire = 100))]
+        Zero = 0,
+        #[num_enum(catch_all)]
+        Unknown(u16),
+    }
+
+    assert_eq!(Enum::try_from_code::<EnumWire>(100), Ok(Enum::Zero));
+    assert_eq!(Enum::try_from_code::<EnumWire>(0), Ok(Enum::Unknown(0)));
+    assert_eq!(Enum::Unknown(7).code::<EnumWire>(), 7);
+    assert_eq!(Enum::Zero.code::<EnumWire>(), 100);
+}
+
+#[test]
+fn same_table_in_two_enums() {
+    #[derive(Debug, Eq, PartialEq, ::num_enum::CodeTables)]
+    #[repr(u8)]
+    enum Audio {
+        #[num_enum(code(rtp = 0))]
+        Mulaw,
+    }
+
+    #[derive(Debug, Eq, PartialEq, ::num_enum::CodeTables)]
+    #[repr(u8)]
+    enum Video {
+        #[num_enum(code(rtp = 26))]
+        Jpeg,
+    }
+
+    assert_eq!(Audio::Mulaw.code::<AudioRtp>(), 0);
+    assert_eq!(Video::try_from_code::<VideoRtp>(26), Ok(Video::Jpeg));
+}
@@ -0,0 +1,161 @@
+use ::std::convert::TryFrom;
+
+// Guard against https://github.com/illicitonion/num_enum/issues/27
+mod alloc {}
+mod core {}
+mod num_enum {}
+mod std {}
+
+mod wire {
+    #[derive(Debug, Eq, PartialEq, ::num_enum::TryFromPrimitive)]
+    #[repr(u8)]
+    pub enum Kind {
+        Request = 1,
+        Response = 2,
+        Legacy = 0x7f,
+    }
+}
+
+#[test]
+fn by_name() {
+    #[derive(Debug, ::num_enum::IntoPrimitive)]
+    #[num_enum(convert(to = wire::Kind, by = "name"))]
+    #[repr(u8)]
+    enum Kind {
+        Response,
+        Request,
+        #[num_enum(maps_to = wire::Kind::Legacy)]
+        OldRequest,
+    }
+
+    assert_eq!(wire::Kind::from(Kind::Request), wire::Kind::Request);
+    assert_eq!(wire::Kind::from(Kind::Response), wire::Kind::Response);
+    assert_eq!(wire::Kind::from(Kind::OldRequest), wire::Kind::Legacy);
+}
+
+#[test]
+fn by_value() {
+    const RESPONSE: u8 = 2;
+
+    #[derive(Debug, ::num_enum::IntoPrimitive)]
+    #[num_enum(convert(to = wire::Kind, by = "value"))]
+    #[repr(u8)]
+    enum Kind {
+        Request = 1,
+        Response = RESPONSE,
+        #[num_enum(maps_to = wire::Kind::Request)]
+        Retry,
+    }
+
+    assert_eq!(wire::Kind::from(Kind::Request), wire::Kind::Request);
+    assert_eq!(wire::Kind::from(Kind::Response), wire::Kind::Response);
+    assert_eq!(wire::Kind::from(Kind::Retry), wire::Kind::Request);
+}
+
+#[test]
+fn by_value_fallible() {
+    // Without `fallible`, `Unsupported` having no counterpart in `wire::Kind` is a compile error
+    // (see compile_fail/convert_missing_value_counterpart.rs).
+    #[derive(Debug, ::num_enum::IntoPrimitive)]
+    #[num_enum(convert(to = wire::Kind, by = "value", fallible))]
+    #[repr(u8)]
+    enum Kind {
+        Request = 1,
+        Response = 2,
+        Unsupported = 3,
+    }
+
+    assert_eq!(wire::Kind::try_from(Kind::Request), Ok(wire::Kind::Request));
+    assert_eq!(
+        wire::Kind::try_from(Kind::Response),
+        Ok(wire::Kind::Response)
+    );
+    let err = wire::Kind::try_from(Kind::Unsupported).unwrap_err();
+    assert_eq!(err.number, 3);
+}
+
+#[test]
+fn by_value_fallible_fully_mapped() {
+    #[derive(Debug, ::num_enum::IntoPrimitive)]
+    #[num_enum(convert(to = wire::Kind, by = "value", fallible))]
+    #[repr(u8)]
+    enum Kind {
+        Request = 1,
+        Legacy = 0x7f,
+    }
+
+    assert_eq!(wire::Kind::try_from(Kind::Request), Ok(wire::Kind::Request));
+    assert_eq!(wire::Kind::try_from(Kind::Legacy), Ok(wire::Kind::Legacy));
+}
+
+#[test]
+fn by_value_with_overrides() {
+    #[derive(Debug, ::num_enum::IntoPrimitive)]
+    #[num_enum(convert(to = wire::Kind, by = "value"))]
+    #[repr(u8)]
+    enum Kind {
+        Request = 1,
+        #[num_enum(maps_to = wire::Kind::Legacy)]
+        OldRequest = 10,
+        #[num_enum(catch_all)]
+        Other(u8),
+    }
+
+    assert_eq!(wire::Kind::try_from(Kind::Request), Ok(wire::Kind::Request));
+    assert_eq!(
+        wire::Kind::try_from(Kind::OldRequest),
+        Ok(wire::Kind::Legacy)
+    );
+    assert_eq!(
+        wire::Kind::try_from(Kind::Other(2)),
+        Ok(wire::Kind::Response)
+    );
+    assert!(wire::Kind::try_from(Kind::Other(10)).is_err());
+}
+
+#[test]
+fn by_value_fully_overridden_is_infallible() {
+    #[derive(Debug, ::num_enum::IntoPrimitive)]
+    #[num_enum(convert(to = wire::Kind, by = "value"))]
+    #[repr(u8)]
+    enum Kind {
+        #[num_enum(maps_to = wire::Kind::Request)]
+        Ask = 10,
+        #[num_enum(maps_to = wire::Kind::Response)]
+        Answer = 20,
+    }
+
+    assert_eq!(wire::Kind::from(Kind::Ask), wire::Kind::Request);
+    assert_eq!(wire::Kind::from(Kind::Answer), wire::Kind::Response);
+}
+
+#[test]
+fn multiple_targets() {
+    #[derive(Debug, Eq, PartialEq, ::num_enum::IntoPrimitive)]
+    #[repr(u8)]
+    enum Colour {
+        Red,
+        Green,
+        Blue,
+    }
+
+    #[derive(Debug, ::num_enum::IntoPrimitive)]
+    #[num_enum(convert(to = Colour, by = "name"))]
+    #[num_enum(convert(to = wire::Kind, by = "value"))]
+    #[repr(u8)]
+    enum Signal {
+        Red = 1,
+        #[num_enum(maps_to = Colour::Green)]
+        Amber = 2,
+        #[num_enum(maps_to = wire::Kind::Legacy)]
+        Blue = 3,
+    }
+
+    assert_eq!(Colour::from(Signal::Red), Colour::Red);
+    assert_eq!(Colour::from(Signal::Amber), Colour::Green);
+    assert_eq!(Colour::from(Signal::Blue), Colour::Blue);
+
+    assert_eq!(wire::Kind::from(Signal::Red), wire::Kind::Request);
+    assert_eq!(wire::Kind::from(Signal::Amber), wire::Kind::Response);
+    assert_eq!(wire::Kind::from(Signal::Blue), wire::Kind::Legacy);
+}
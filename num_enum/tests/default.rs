@@ -31,3 +31,18 @@ fn default_standard_default_attribute() {
 
     assert_eq!(Enum::NonZero, <Enum as ::core::default::Default>::default());
 }
+
+#[test]
+fn default_ignores_fallback() {
+    #[derive(Debug, Eq, PartialEq, ::num_enum::Default)]
+    #[repr(u8)]
+    enum Enum {
+        #[default]
+        Idle = 0,
+        #[allow(unused)]
+        #[num_enum(fallback)]
+        Unsupported = 1,
+    }
+
+    assert_eq!(Enum::Idle, <Enum as ::core::default::Default>::default());
+}
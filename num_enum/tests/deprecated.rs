@@ -0,0 +1,196 @@
+// Deprecation warnings from generated code would otherwise fail this whole file.
+#![deny(deprecated)]
+
+use ::std::cell::RefCell;
+use ::std::convert::TryFrom;
+use ::std::vec::Vec;
+
+// Guard against https://github.com/illicitonion/num_enum/issues/27
+mod alloc {}
+mod core {}
+mod num_enum {}
+mod std {}
+
+#[test]
+fn deprecated_variants() {
+    #[derive(
+        Debug,
+        Clone,
+        Copy,
+        Eq,
+        PartialEq,
+        ::num_enum::IntoPrimitive,
+        ::num_enum::FromPrimitive,
+        ::num_enum::UnsafeFromPrimitive,
+        ::num_enum::EnumMetadata,
+        ::num_enum::Ordinal,
+        ::num_enum::Default,
+    )]
+    #[repr(u8)]
+    enum Enum {
+        Zero,
+        #[deprecated]
+        #[num_enum(alternatives = [3])]
+        One,
+        #[deprecated]
+        #[num_enum(default)]
+        Two,
+    }
+
+    assert_eq!(Enum::from(0), Enum::Zero);
+    assert_eq!(u8::from(Enum::Zero), 0);
+    assert_eq!(Enum::from(3).ordinal(), 1);
+}
+
+#[test]
+fn deprecated_catch_all() {
+    #[derive(Debug, Eq, PartialEq, ::num_enum::IntoPrimitive, ::num_enum::FromPrimitive)]
+    #[repr(u8)]
+    enum Enum {
+        Zero,
+        #[deprecated]
+        #[num_enum(catch_all)]
+        NonZero(u8),
+    }
+
+    assert_eq!(Enum::from(0), Enum::Zero);
+    assert_eq!(u8::from(Enum::from(5)), 5);
+}
+
+#[test]
+fn deprecated_enum() {
+    #[deprecated]
+    #[derive(
+        Debug,
+        Eq,
+        PartialEq,
+        ::num_enum::IntoPrimitive,
+        ::num_enum::TryFromPrimitive,
+        ::num_enum::UnsafeFromPrimitive,
+        ::num_enum::EnumMetadata,
+        ::num_enum::Ordinal,
+    )]
+    #[repr(u8)]
+    enum Enum {
+        Zero,
+        One,
+    }
+
+    #[deprecated]
+    #[derive(::num_enum::TaggedPayload)]
+    #[repr(u8)]
+    #[allow(dead_code)]
+    enum Message {
+        Ping(u32),
+        #[deprecated]
+        Pong {
+            id: u32,
+        },
+    }
+}
+
+::std::thread_local! {
+    static DEPRECATED_VALUES: RefCell<Vec<(&'static str, u8)>> = const { RefCell::new(Vec::new()) };
+}
+
+fn record_deprecated_value(variant: &'static str, value: u8) {
+    DEPRECATED_VALUES.with(|values| values.borrow_mut().push((variant, value)));
+}
+
+fn take_deprecated_values() -> Vec<(&'static str, u8)> {
+    DEPRECATED_VALUES.with(|values| values.take())
+}
+
+#[test]
+fn from_primitive_warning_hook() {
+    #[derive(Debug, Eq, PartialEq, ::num_enum::FromPrimitive)]
+    #[num_enum(deprecated_value_warning = record_deprecated_value)]
+    #[repr(u8)]
+    enum Enum {
+        Zero,
+        #[deprecated]
+        #[num_enum(alternatives = [3])]
+        One,
+        #[num_enum(default)]
+        Other,
+    }
+
+    assert_eq!(Enum::from(0), Enum::Zero);
+    assert_eq!(Enum::from(5), Enum::Other);
+    assert_eq!(take_deprecated_values(), []);
+
+    assert_eq!(Enum::from(1), Enum::from(3));
+    assert_eq!(
+        take_deprecated_values(),
+        [("Enum::One", 1), ("Enum::One", 3)]
+    );
+}
+
+#[test]
+fn try_from_primitive_warning_hook() {
+    #[derive(Debug, Eq, PartialEq, ::num_enum::TryFromPrimitive)]
+    #[num_enum(deprecated_value_warning = self::record_deprecated_value)]
+    #[repr(u8)]
+    enum Enum {
+        #[deprecated]
+        Zero,
+        One,
+    }
+
+    assert!(Enum::try_from(1).is_ok());
+    assert!(Enum::try_from(2).is_err());
+    assert_eq!(take_deprecated_values(), []);
+
+    assert!(Enum::try_from(0).is_ok());
+    assert_eq!(take_deprecated_values(), [("Enum::Zero", 0)]);
+}
+
+#[cfg(feature = "log")]
+mod log {
+    use ::std::sync::Mutex;
+    use ::std::vec::Vec;
+
+    static MESSAGES: Mutex<Vec<::std::string::String>> = Mutex::new(Vec::new());
+
+    struct Logger;
+
+    impl ::log::Log for Logger {
+        fn enabled(&self, metadata: &::log::Metadata) -> bool {
+            metadata.level() <= ::log::Level::Warn
+        }
+
+        fn log(&self, record: &::log::Record) {
+            if self.enabled(record.metadata()) {
+                MESSAGES
+                    .lock()
+                    .unwrap()
+                    .push(::std::format!("{}", record.args()));
+            }
+        }
+
+        fn flush(&self) {}
+    }
+
+    #[test]
+    fn warning_logged() {
+        #[derive(Debug, Eq, PartialEq, ::num_enum::FromPrimitive)]
+        #[num_enum(deprecated_value_warning)]
+        #[repr(i8)]
+        enum Enum {
+            #[deprecated]
+            MinusOne = -1,
+            #[num_enum(default)]
+            Zero,
+        }
+
+        ::log::set_logger(&Logger).unwrap();
+        ::log::set_max_level(::log::LevelFilter::Warn);
+
+        assert_eq!(Enum::from(0), Enum::Zero);
+        let _ = Enum::from(-1);
+        assert_eq!(
+            *MESSAGES.lock().unwrap(),
+            ["Converted -1 into deprecated variant `Enum::MinusOne`"]
+        );
+    }
+}
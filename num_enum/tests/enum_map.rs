@@ -0,0 +1,100 @@
+use ::num_enum::{EnumMap, Ordinal};
+
+// Guard against https://github.com/illicitonion/num_enum/issues/27
+mod alloc {}
+mod core {}
+mod num_enum {}
+mod std {}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ordinal)]
+#[repr(u8)]
+enum Opcode {
+    Nop = 0x10,
+    Load = 0x20,
+    Store = 0x80,
+}
+
+#[test]
+fn from_fn() {
+    let map = EnumMap::from_fn(|opcode: Opcode| u8::try_from(opcode.ordinal()).unwrap() * 10);
+
+    assert_eq!(map[Opcode::Nop], 0);
+    assert_eq!(map[Opcode::Load], 10);
+    assert_eq!(map[Opcode::Store], 20);
+    assert_eq!(map.len(), 3);
+    assert!(!map.is_empty());
+}
+
+#[test]
+fn index_mut() {
+    let mut map: EnumMap<Opcode, u32> = EnumMap::default();
+    map[Opcode::Store] += 5;
+    *map.get_mut(Opcode::Nop) = 1;
+
+    assert_eq!(map.as_slice(), &[1, 0, 5]);
+    assert_eq!(*map.get(Opcode::Store), 5);
+}
+
+#[test]
+fn iteration_yields_variants_in_declaration_order() {
+    let map = EnumMap::from_fn(|opcode: Opcode| opcode as u8);
+
+    let pairs: Vec<(Opcode, u8)> = map.iter().map(|(opcode, value)| (opcode, *value)).collect();
+    assert_eq!(
+        pairs,
+        vec![
+            (Opcode::Nop, 0x10),
+            (Opcode::Load, 0x20),
+            (Opcode::Store, 0x80)
+        ],
+    );
+
+    let reversed: Vec<Opcode> = map.iter().rev().map(|(opcode, _)| opcode).collect();
+    assert_eq!(reversed, vec![Opcode::Store, Opcode::Load, Opcode::Nop]);
+
+    assert_eq!(map.iter().len(), 3);
+    assert_eq!(
+        map.values().copied().collect::<Vec<_>>(),
+        vec![0x10, 0x20, 0x80]
+    );
+}
+
+#[test]
+fn iter_mut() {
+    let mut map: EnumMap<Opcode, Vec<Opcode>> = EnumMap::default();
+    for (opcode, value) in &mut map {
+        value.push(opcode);
+    }
+    for value in map.values_mut() {
+        value.push(Opcode::Nop);
+    }
+
+    assert_eq!(map[Opcode::Load], vec![Opcode::Load, Opcode::Nop]);
+}
+
+#[test]
+fn clone_eq_and_debug() {
+    let map = EnumMap::from_fn(|opcode: Opcode| opcode.ordinal());
+    let mut cloned = map.clone();
+    assert_eq!(map, cloned);
+
+    cloned[Opcode::Nop] = 7;
+    assert_ne!(map, cloned);
+
+    assert_eq!(format!("{:?}", map), "{Nop: 0, Load: 1, Store: 2}");
+}
+
+#[test]
+fn non_copy_keys() {
+    #[derive(Debug, Eq, PartialEq, Ordinal)]
+    #[repr(i16)]
+    enum Level {
+        Low = -100,
+        High = 100,
+    }
+
+    let mut map = EnumMap::from_fn(|level: Level| level == Level::High);
+    map[Level::Low] = true;
+
+    assert!(map.values().all(|high| *high));
+}
@@ -0,0 +1,224 @@
+// The metadata under test is made of associated consts, so asserting on constants is the point.
+#![allow(clippy::assertions_on_constants)]
+
+use ::num_enum::EnumMetadata;
+
+// Guard against https://github.com/illicitonion/num_enum/issues/27
+mod alloc {}
+mod core {}
+mod num_enum {}
+mod std {}
+
+#[test]
+fn simple() {
+    #[derive(EnumMetadata)]
+    #[repr(u8)]
+    #[allow(unused)]
+    enum Enum {
+        Zero,
+        One,
+        Two,
+    }
+
+    assert_eq!(Enum::MIN_DISCRIMINANT, 0);
+    assert_eq!(Enum::MAX_DISCRIMINANT, 2);
+    assert!(!Enum::IS_EXHAUSTIVE);
+    assert!(!Enum::HAS_DEFAULT);
+    assert!(!Enum::HAS_CATCH_ALL);
+    assert!(Enum::IS_CONTIGUOUS);
+}
+
+#[test]
+fn sparse_and_out_of_order() {
+    #[derive(EnumMetadata)]
+    #[repr(u8)]
+    #[allow(unused)]
+    enum Enum {
+        Sixteen = 0x10,
+        ThirtyTwo = 0x20,
+        Eight = 0x08,
+        OneTwentyEight = 0x80,
+    }
+
+    assert_eq!(Enum::MIN_DISCRIMINANT, 0x08);
+    assert_eq!(Enum::MAX_DISCRIMINANT, 0x80);
+    assert!(!Enum::IS_CONTIGUOUS);
+}
+
+#[test]
+fn negative_values() {
+    #[derive(EnumMetadata)]
+    #[repr(i8)]
+    #[allow(unused)]
+    enum Enum {
+        Zero = 0,
+        MinusTwo = -2,
+        MinusOne,
+        One = 1,
+    }
+
+    assert_eq!(Enum::MIN_DISCRIMINANT, -2);
+    assert_eq!(Enum::MAX_DISCRIMINANT, 1);
+    assert!(Enum::IS_CONTIGUOUS);
+}
+
+#[test]
+fn full_range_128_bit() {
+    #[derive(EnumMetadata)]
+    #[repr(u128)]
+    #[allow(unused)]
+    enum Unsigned {
+        Zero = 0,
+        Max = u128::MAX,
+    }
+
+    #[derive(EnumMetadata)]
+    #[repr(i128)]
+    #[allow(unused)]
+    enum Signed {
+        Min = i128::MIN,
+        Max = i128::MAX,
+    }
+
+    #[derive(EnumMetadata)]
+    #[repr(i128)]
+    #[allow(unused)]
+    enum Adjacent {
+        MinusOne = -1,
+        Zero,
+    }
+
+    assert_eq!(Unsigned::MAX_DISCRIMINANT, u128::MAX);
+    assert!(!Unsigned::IS_CONTIGUOUS);
+    assert_eq!(Signed::MIN_DISCRIMINANT, i128::MIN);
+    assert!(!Signed::IS_CONTIGUOUS);
+    assert!(Adjacent::IS_CONTIGUOUS);
+}
+
+#[test]
+fn discriminant_expressions() {
+    const ONE: u8 = 1;
+
+    #[derive(EnumMetadata)]
+    #[repr(u8)]
+    #[allow(unused)]
+    enum Enum {
+        One = ONE,
+        Two,
+        Three = ONE + ONE + 1u8,
+        Four = b'\x04',
+    }
+
+    assert_eq!(Enum::MIN_DISCRIMINANT, 1);
+    assert_eq!(Enum::MAX_DISCRIMINANT, 4);
+    assert!(Enum::IS_CONTIGUOUS);
+}
+
+#[test]
+fn alternatives_do_not_affect_bounds() {
+    #[derive(EnumMetadata)]
+    #[repr(u8)]
+    #[allow(unused)]
+    enum Enum {
+        #[num_enum(alternatives = [0])]
+        One = 1,
+        #[num_enum(alternatives = [10])]
+        Two,
+    }
+
+    assert_eq!(Enum::MIN_DISCRIMINANT, 1);
+    assert_eq!(Enum::MAX_DISCRIMINANT, 2);
+    assert!(Enum::IS_CONTIGUOUS);
+}
+
+#[test]
+fn default() {
+    #[derive(EnumMetadata)]
+    #[repr(u16)]
+    #[allow(unused)]
+    enum Enum {
+        Zero,
+        #[num_enum(default)]
+        One,
+    }
+
+    assert!(!Enum::IS_EXHAUSTIVE);
+    assert!(Enum::HAS_DEFAULT);
+    assert!(!Enum::HAS_FALLBACK);
+    assert!(!Enum::HAS_CATCH_ALL);
+}
+
+#[test]
+fn fallback() {
+    #[derive(EnumMetadata)]
+    #[repr(u16)]
+    #[allow(unused)]
+    enum Enum {
+        #[default]
+        Zero,
+        #[num_enum(fallback)]
+        One,
+    }
+
+    assert!(!Enum::IS_EXHAUSTIVE);
+    assert!(Enum::HAS_DEFAULT);
+    assert!(Enum::HAS_FALLBACK);
+    assert!(!Enum::HAS_CATCH_ALL);
+}
+
+#[test]
+fn catch_all() {
+    #[derive(EnumMetadata)]
+    #[repr(u8)]
+    #[allow(unused)]
+    enum Enum {
+        Two = 2,
+        #[num_enum(catch_all)]
+        Other(u8) = 0,
+        Three = 3,
+    }
+
+    assert_eq!(Enum::MIN_DISCRIMINANT, 2);
+    assert_eq!(Enum::MAX_DISCRIMINANT, 3);
+    assert!(!Enum::HAS_DEFAULT);
+    assert!(Enum::HAS_CATCH_ALL);
+    assert!(Enum::IS_CONTIGUOUS);
+}
+
+#[test]
+fn usable_in_generic_const_code() {
+    #[derive(EnumMetadata)]
+    #[repr(u8)]
+    #[allow(unused)]
+    enum Enum {
+        Zero,
+        One,
+        Two,
+    }
+
+    fn table_len<E: EnumMetadata<Primitive = u8>>() -> usize {
+        usize::from(E::MAX_DISCRIMINANT) + 1
+    }
+
+    const MAX: u8 = Enum::MAX_DISCRIMINANT;
+    let table = [0u32; MAX as usize + 1];
+
+    assert_eq!(table.len(), table_len::<Enum>());
+}
+
+#[cfg(feature = "complex-expressions")]
+#[test]
+fn exhaustive_via_alternatives() {
+    #[derive(EnumMetadata)]
+    #[repr(u8)]
+    #[allow(unused)]
+    enum Enum {
+        Zero = 0,
+        #[num_enum(alternatives = [2..=255])]
+        NonZero = 1,
+    }
+
+    assert!(Enum::IS_EXHAUSTIVE);
+    assert!(!Enum::HAS_DEFAULT);
+    assert!(Enum::IS_CONTIGUOUS);
+}
@@ -0,0 +1,92 @@
+use ::std::ffi::CStr;
+use ::std::os::raw::c_char;
+
+// Guard against https://github.com/illicitonion/num_enum/issues/27
+mod alloc {}
+mod core {}
+mod num_enum {}
+mod std {}
+
+#[derive(Debug, Eq, PartialEq, ::num_enum::TryFromPrimitive)]
+#[num_enum(ffi_export(prefix = "ffi_test_opcode"))]
+#[repr(u8)]
+#[allow(dead_code)]
+enum Opcode {
+    Nop = 0,
+    #[num_enum(alternatives = [0x11])]
+    Add = 0x10,
+    Halt = 0xff,
+}
+
+#[derive(Debug, Eq, PartialEq, ::num_enum::TryFromPrimitive)]
+#[num_enum(ffi_export(prefix = "ffi_test_number"))]
+#[repr(i16)]
+#[allow(dead_code)]
+enum Number {
+    MinusOne = -1,
+    Thousand = 1000,
+}
+
+// The functions are only reachable through their symbols, as they would be from C.
+extern "C" {
+    fn ffi_test_opcode_is_valid(value: u8) -> bool;
+    fn ffi_test_opcode_name(value: u8) -> *const c_char;
+    fn ffi_test_opcode_try_from(value: u8, out: Option<&mut u8>) -> bool;
+
+    fn ffi_test_number_is_valid(value: i16) -> bool;
+    fn ffi_test_number_name(value: i16) -> *const c_char;
+    fn ffi_test_number_try_from(value: i16, out: Option<&mut i16>) -> bool;
+}
+
+fn name(ptr: *const c_char) -> Option<&'static str> {
+    if ptr.is_null() {
+        None
+    } else {
+        Some(unsafe { CStr::from_ptr(ptr) }.to_str().unwrap())
+    }
+}
+
+#[test]
+fn is_valid() {
+    unsafe {
+        assert!(ffi_test_opcode_is_valid(0));
+        assert!(ffi_test_opcode_is_valid(0x11));
+        assert!(ffi_test_opcode_is_valid(0xff));
+        assert!(!ffi_test_opcode_is_valid(1));
+
+        assert!(ffi_test_number_is_valid(-1));
+        assert!(!ffi_test_number_is_valid(i16::MAX));
+    }
+}
+
+#[test]
+fn names() {
+    unsafe {
+        assert_eq!(name(ffi_test_opcode_name(0)), Some("Nop"));
+        assert_eq!(name(ffi_test_opcode_name(0x11)), Some("Add"));
+        assert_eq!(name(ffi_test_opcode_name(2)), None);
+
+        assert_eq!(name(ffi_test_number_name(-1)), Some("MinusOne"));
+        assert_eq!(name(ffi_test_number_name(1000)), Some("Thousand"));
+        assert_eq!(name(ffi_test_number_name(7)), None);
+    }
+}
+
+#[test]
+fn try_from() {
+    unsafe {
+        let mut out = 0xaa;
+        assert!(ffi_test_opcode_try_from(0x11, Some(&mut out)));
+        assert_eq!(out, 0x10);
+        assert!(ffi_test_opcode_try_from(0xff, Some(&mut out)));
+        assert_eq!(out, 0xff);
+        assert!(!ffi_test_opcode_try_from(3, Some(&mut out)));
+        assert_eq!(out, 0xff);
+        assert!(ffi_test_opcode_try_from(0, None));
+
+        let mut out = 0;
+        assert!(ffi_test_number_try_from(-1, Some(&mut out)));
+        assert_eq!(out, -1);
+        assert!(!ffi_test_number_try_from(-5, Some(&mut out)));
+    }
+}
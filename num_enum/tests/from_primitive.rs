@@ -94,6 +94,29 @@ fn from_primitive_number() {
     assert_eq!(from, Enum::Whatever);
 }
 
+#[test]
+fn from_primitive_number_fallback() {
+    #[derive(Debug, Eq, PartialEq, FromPrimitive, ::num_enum::Default)]
+    #[repr(u8)]
+    enum Enum {
+        #[default]
+        Idle = 0,
+        Busy = 1,
+        #[num_enum(fallback)]
+        Unsupported = 2,
+    }
+
+    assert_eq!(Enum::from_primitive(0_u8), Enum::Idle);
+    assert_eq!(Enum::from_primitive(1_u8), Enum::Busy);
+    assert_eq!(Enum::from_primitive(2_u8), Enum::Unsupported);
+    assert_eq!(Enum::from_primitive(3_u8), Enum::Unsupported);
+    assert_eq!(
+        Enum::from_primitive_reporting(3_u8),
+        (Enum::Unsupported, true)
+    );
+    assert_eq!(<Enum as ::core::default::Default>::default(), Enum::Idle);
+}
+
 #[test]
 fn from_primitive_number_catch_all() {
     #[derive(Debug, Eq, PartialEq, FromPrimitive)]
@@ -164,3 +187,166 @@ fn from_primitive_number_with_inclusive_range() {
     let twofivefive = Enum::from_primitive(255_u8);
     assert_eq!(twofivefive, Enum::NonZero);
 }
+
+#[test]
+fn from_primitive_reporting() {
+    #[derive(Debug, Eq, PartialEq, FromPrimitive)]
+    #[repr(u8)]
+    enum Enum {
+        Zero = 0,
+        #[num_enum(alternatives = [2])]
+        One = 1,
+        #[num_enum(default)]
+        Other = 3,
+    }
+
+    assert_eq!(Enum::from_primitive_reporting(0_u8), (Enum::Zero, false));
+    assert_eq!(Enum::from_primitive_reporting(2_u8), (Enum::One, false));
+    assert_eq!(Enum::from_primitive_reporting(3_u8), (Enum::Other, false));
+    assert_eq!(Enum::from_primitive_reporting(4_u8), (Enum::Other, true));
+}
+
+#[test]
+fn from_primitive_reporting_catch_all() {
+    #[derive(Debug, Eq, PartialEq, FromPrimitive)]
+    #[repr(u8)]
+    enum Enum {
+        Zero = 0,
+        #[num_enum(catch_all)]
+        NonZero(u8),
+    }
+
+    assert_eq!(Enum::from_primitive_reporting(0_u8), (Enum::Zero, false));
+    assert_eq!(
+        Enum::from_primitive_reporting(7_u8),
+        (Enum::NonZero(7_u8), true)
+    );
+}
+
+#[cfg(feature = "complex-expressions")]
+#[test]
+fn from_primitive_reporting_exhaustive() {
+    #[derive(Debug, Eq, PartialEq, FromPrimitive)]
+    #[repr(u8)]
+    enum Enum {
+        Zero = 0,
+        #[num_enum(alternatives = [2..=255])]
+        NonZero = 1,
+    }
+
+    assert_eq!(Enum::from_primitive_reporting(0_u8), (Enum::Zero, false));
+    assert_eq!(Enum::from_primitive_reporting(9_u8), (Enum::NonZero, false));
+}
+
+::std::thread_local! {
+    static UNKNOWN_VALUES: ::std::cell::RefCell<::std::vec::Vec<(&'static str, i16)>> =
+        const { ::std::cell::RefCell::new(::std::vec::Vec::new()) };
+}
+
+fn record_unknown_value(name: &'static str, value: i16) {
+    UNKNOWN_VALUES.with(|values| values.borrow_mut().push((name, value)));
+}
+
+#[test]
+fn on_unknown() {
+    #[derive(Debug, Eq, PartialEq, FromPrimitive)]
+    #[num_enum(on_unknown = record_unknown_value)]
+    #[repr(i16)]
+    enum Enum {
+        MinusOne = -1,
+        #[num_enum(default)]
+        Zero = 0,
+    }
+
+    #[derive(Debug, Eq, PartialEq, FromPrimitive)]
+    #[num_enum(on_unknown = self::record_unknown_value)]
+    #[repr(i16)]
+    enum CatchAll {
+        Zero = 0,
+        #[num_enum(catch_all)]
+        NonZero(i16),
+    }
+
+    assert_eq!(Enum::from_primitive(-1), Enum::MinusOne);
+    assert_eq!(Enum::from_primitive(0), Enum::Zero);
+    assert_eq!(Enum::from_primitive(5), Enum::Zero);
+    assert_eq!(Enum::from_primitive_reporting(6), (Enum::Zero, true));
+    assert_eq!(CatchAll::from_primitive(0), CatchAll::Zero);
+    assert_eq!(CatchAll::from(-7), CatchAll::NonZero(-7));
+
+    let unknown_values = UNKNOWN_VALUES.with(|values| values.take());
+    assert_eq!(unknown_values, [("Enum", 5), ("Enum", 6), ("CatchAll", -7)]);
+}
+
+#[test]
+fn normalize() {
+    #[derive(Debug, Eq, PartialEq, FromPrimitive)]
+    #[repr(u8)]
+    enum Enum {
+        Zero = 0,
+        Three = 3,
+        #[num_enum(catch_all)]
+        Unknown(u8),
+    }
+
+    assert_eq!(Enum::Unknown(3).normalize(), Enum::Three);
+    assert_eq!(Enum::Unknown(4).normalize(), Enum::Unknown(4));
+    assert_eq!(Enum::Zero.normalize(), Enum::Zero);
+}
+
+#[test]
+fn skipped_variant() {
+    #[derive(Debug, Eq, PartialEq, FromPrimitive)]
+    #[repr(u8)]
+    #[allow(dead_code)]
+    enum Enum {
+        #[num_enum(skip(strict))]
+        Uninitialized,
+        One,
+        #[num_enum(default)]
+        Other,
+    }
+
+    assert_eq!(Enum::from(0), Enum::Other);
+    assert_eq!(Enum::from(1), Enum::One);
+    assert_eq!(Enum::from(2), Enum::Other);
+}
+
+// Skipped variants don't count towards exhaustiveness, so this needs a fallback.
+#[cfg(feature = "complex-expressions")]
+#[test]
+fn skipped_variant_not_exhaustive() {
+    #[derive(Debug, Eq, PartialEq, FromPrimitive)]
+    #[repr(i8)]
+    #[allow(dead_code)]
+    enum Enum {
+        #[num_enum(alternatives = [-128..=-2, 3..=127])]
+        Other = -1,
+        Zero = 0,
+        #[num_enum(skip)]
+        Invalid = 1,
+        #[num_enum(fallback)]
+        Fallback = 2,
+    }
+
+    assert_eq!(Enum::from(0), Enum::Zero);
+    assert_eq!(Enum::from(1), Enum::Fallback);
+    assert_eq!(Enum::from(100), Enum::Other);
+}
+
+#[test]
+fn exhaustive_with_masks() {
+    #[derive(Debug, Eq, PartialEq, FromPrimitive)]
+    #[repr(u8)]
+    enum Enum {
+        #[num_enum(mask = 0x80, value = 0x00)]
+        Low = 0,
+        #[num_enum(mask = 0x80, value = 0x80)]
+        High = 0x80,
+    }
+
+    assert_eq!(Enum::from(0), Enum::Low);
+    assert_eq!(Enum::from(0x7F), Enum::Low);
+    assert_eq!(Enum::from(0x80), Enum::High);
+    assert_eq!(Enum::from(0xFF), Enum::High);
+}
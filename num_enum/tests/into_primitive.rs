@@ -45,3 +45,125 @@ fn catch_all() {
     let two: u8 = Enum::NonZero(2u8).into();
     assert_eq!(two, 2u8);
 }
+
+#[test]
+fn catch_all_with_discriminant_expressions() {
+    const ONE: u8 = 1;
+
+    #[derive(Debug, Eq, PartialEq, IntoPrimitive)]
+    #[repr(u8)]
+    enum Enum {
+        One = ONE,
+        Two,
+        #[num_enum(catch_all)]
+        Other(u8) = 100,
+        Ten = ONE + 9u8,
+        Eleven,
+    }
+
+    assert_eq!(u8::from(Enum::One), 1u8);
+    assert_eq!(u8::from(Enum::Two), 2u8);
+    assert_eq!(u8::from(Enum::Ten), 10u8);
+    assert_eq!(u8::from(Enum::Eleven), 11u8);
+    assert_eq!(u8::from(Enum::Other(100)), 100u8);
+    assert_eq!(u8::from(Enum::Other(3)), 3u8);
+}
+
+#[test]
+fn variants_with_fields() {
+    const PONG: u8 = 2;
+
+    #[derive(IntoPrimitive)]
+    #[repr(u8)]
+    #[allow(dead_code)]
+    enum Msg {
+        Ping { id: u32 } = 1,
+        Pong { id: u32 } = PONG,
+        Data(::std::vec::Vec<u8>),
+        Close = 10,
+        Reset(),
+    }
+
+    assert_eq!(u8::from(Msg::Ping { id: 7 }), 1u8);
+    assert_eq!(u8::from(Msg::Pong { id: 7 }), 2u8);
+    assert_eq!(u8::from(Msg::Data(vec![1, 2])), 3u8);
+    assert_eq!(u8::from(Msg::Close), 10u8);
+    assert_eq!(u8::from(Msg::Reset()), 11u8);
+
+    let data = Msg::Data(vec![]);
+    assert_eq!(u8::from(&data), 3u8);
+    let opcode: u8 = (&Msg::Ping { id: 0 }).into();
+    assert_eq!(opcode, 1u8);
+}
+
+#[test]
+fn variants_with_fields_and_catch_all() {
+    #[derive(IntoPrimitive)]
+    #[repr(u16)]
+    #[allow(dead_code)]
+    enum Msg {
+        Ping {
+            id: u32,
+        } = 1,
+        Close = 10,
+        #[num_enum(catch_all)]
+        Unknown(u16),
+    }
+
+    assert_eq!(u16::from(&Msg::Ping { id: 7 }), 1u16);
+    assert_eq!(u16::from(Msg::Close), 10u16);
+    assert_eq!(u16::from(&Msg::Unknown(500)), 500u16);
+}
+
+#[test]
+fn catch_all_payloads() {
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    struct RawOpcode(u8);
+
+    impl ::core::convert::From<u8> for RawOpcode {
+        fn from(raw: u8) -> Self {
+            RawOpcode(raw)
+        }
+    }
+
+    impl ::core::convert::From<RawOpcode> for u8 {
+        fn from(opcode: RawOpcode) -> Self {
+            opcode.0
+        }
+    }
+
+    #[derive(Debug, Eq, PartialEq, IntoPrimitive, ::num_enum::FromPrimitive)]
+    #[repr(u8)]
+    enum Qualified {
+        Zero,
+        #[num_enum(catch_all)]
+        Unknown(::core::primitive::u8),
+    }
+
+    #[derive(Debug, Eq, PartialEq, IntoPrimitive, ::num_enum::FromPrimitive)]
+    #[repr(u8)]
+    enum Named {
+        Zero,
+        #[num_enum(catch_all)]
+        Unknown {
+            value: u8,
+        },
+    }
+
+    #[derive(Debug, Eq, PartialEq, IntoPrimitive, ::num_enum::FromPrimitive)]
+    #[repr(u8)]
+    enum Newtype {
+        Zero,
+        #[num_enum(catch_all)]
+        Unknown(RawOpcode),
+    }
+
+    assert_eq!(Qualified::from(3), Qualified::Unknown(3));
+    assert_eq!(u8::from(Qualified::Unknown(3)), 3);
+    assert_eq!(Named::from(0), Named::Zero);
+    assert_eq!(Named::from(4), Named::Unknown { value: 4 });
+    assert_eq!(u8::from(Named::Unknown { value: 4 }), 4);
+    assert_eq!(Newtype::from(5), Newtype::Unknown(RawOpcode(5)));
+    assert_eq!(u8::from(Newtype::Unknown(RawOpcode(5))), 5);
+    assert_eq!(u8::from(Newtype::Zero), 0);
+}
@@ -0,0 +1,107 @@
+use ::num_enum::{IntoPrimitive, MaybeKnown, TryFromPrimitive, TryFromPrimitiveError};
+use ::std::collections::hash_map::DefaultHasher;
+use ::std::hash::{Hash, Hasher};
+
+// Guard against https://github.com/illicitonion/num_enum/issues/27
+mod alloc {}
+mod core {}
+mod num_enum {}
+mod std {}
+
+#[derive(Debug, Eq, PartialEq, IntoPrimitive, TryFromPrimitive)]
+#[repr(u16)]
+enum Command {
+    Stop = 1,
+    #[num_enum(alternatives = [3])]
+    Go = 2,
+}
+
+#[test]
+fn known() {
+    let command = MaybeKnown::<Command>::from_raw(2);
+    assert_eq!(command.known(), Ok(Command::Go));
+    assert!(command.is_known());
+
+    let alternative = MaybeKnown::<Command>::from_raw(3);
+    assert_eq!(alternative.known(), Ok(Command::Go));
+    assert_eq!(alternative.raw(), 3);
+
+    assert_eq!(MaybeKnown::from(Command::Stop).raw(), 1);
+}
+
+#[test]
+fn unknown_values_are_preserved() {
+    let command = MaybeKnown::<Command>::from_raw(500);
+    assert_eq!(command.known(), Err(TryFromPrimitiveError::new(500)));
+    assert!(!command.is_known());
+    assert_eq!(command.raw(), 500);
+}
+
+#[test]
+fn custom_error_types_are_not_exposed() {
+    #[derive(Debug, Eq, PartialEq, TryFromPrimitive)]
+    #[num_enum(error_type(name = CustomError, constructor = CustomError::new))]
+    #[repr(u8)]
+    enum Enum {
+        Zero,
+    }
+
+    #[derive(Debug)]
+    struct CustomError;
+
+    impl CustomError {
+        fn new(_: u8) -> Self {
+            CustomError
+        }
+    }
+
+    let value = MaybeKnown::<Enum>::from_raw(1);
+    assert_eq!(value.known().unwrap_err().number, 1);
+    assert_eq!(MaybeKnown::<Enum>::from_raw(0).known(), Ok(Enum::Zero));
+}
+
+#[test]
+fn debug() {
+    assert_eq!(format!("{:?}", MaybeKnown::from(Command::Go)), "Go");
+    assert_eq!(
+        format!("{:?}", MaybeKnown::<Command>::from_raw(9)),
+        "Unknown(9)"
+    );
+}
+
+#[test]
+fn comparisons_use_the_raw_value() {
+    let go = MaybeKnown::from(Command::Go);
+    let go_alternative = MaybeKnown::<Command>::from_raw(3);
+    assert_ne!(go, go_alternative);
+    assert_eq!(go, MaybeKnown::from_raw(2));
+    assert!(MaybeKnown::<Command>::from_raw(1) < go);
+    assert_eq!(::std::cmp::max(go, MaybeKnown::from_raw(100)).raw(), 100);
+
+    fn hash(value: MaybeKnown<Command>) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+    assert_eq!(hash(go), hash(MaybeKnown::from_raw(2)));
+}
+
+#[test]
+fn layout() {
+    #[repr(C)]
+    struct Header {
+        command: MaybeKnown<Command>,
+        length: u16,
+    }
+
+    assert_eq!(::core::mem::size_of::<MaybeKnown<Command>>(), 2);
+    assert_eq!(::core::mem::align_of::<MaybeKnown<Command>>(), 2);
+    assert_eq!(::core::mem::size_of::<Header>(), 4);
+
+    let header = Header {
+        command: MaybeKnown::from_raw(1),
+        length: 0,
+    };
+    assert_eq!(header.command.known(), Ok(Command::Stop));
+    assert_eq!(header.length, 0);
+}
@@ -0,0 +1,69 @@
+use ::num_enum::{FromPrimitive, IntoPrimitive, TryFromPrimitive};
+use ::std::convert::TryFrom;
+use ::std::num::{NonZeroI16, NonZeroU8, NonZeroUsize};
+
+// Guard against https://github.com/illicitonion/num_enum/issues/27
+mod alloc {}
+mod core {}
+mod num_enum {}
+mod std {}
+
+#[test]
+fn try_from_primitive() {
+    #[derive(Debug, Clone, Copy, Eq, PartialEq, IntoPrimitive, TryFromPrimitive)]
+    #[num_enum(nonzero)]
+    #[repr(u8)]
+    enum Enum {
+        One = 1,
+        #[num_enum(alternatives = [4])]
+        Two,
+        Five = 5,
+    }
+
+    let one = NonZeroU8::new(1).unwrap();
+    assert_eq!(NonZeroU8::from(Enum::One), one);
+    assert_eq!(NonZeroU8::from(Enum::Two).get(), 2);
+    assert_eq!(Enum::try_from(one), Ok(Enum::One));
+    assert_eq!(Enum::try_from(NonZeroU8::new(4).unwrap()), Ok(Enum::Two));
+    assert!(Enum::try_from(NonZeroU8::new(3).unwrap()).is_err());
+
+    assert_eq!(
+        ::std::mem::size_of::<Option<NonZeroU8>>(),
+        ::std::mem::size_of::<u8>()
+    );
+}
+
+#[test]
+fn from_primitive() {
+    #[derive(Debug, Eq, PartialEq, IntoPrimitive, FromPrimitive)]
+    #[num_enum(nonzero)]
+    #[repr(i16)]
+    enum Enum {
+        MinusOne = -1,
+        #[num_enum(default)]
+        One = 1,
+    }
+
+    assert_eq!(Enum::from(NonZeroI16::new(-1).unwrap()), Enum::MinusOne);
+    assert_eq!(Enum::from(NonZeroI16::new(9).unwrap()), Enum::One);
+    assert_eq!(NonZeroI16::from(Enum::MinusOne).get(), -1);
+}
+
+const BASE: usize = 10;
+
+#[test]
+fn expression_discriminants() {
+    #[derive(Debug, Eq, PartialEq, IntoPrimitive, TryFromPrimitive)]
+    #[num_enum(nonzero)]
+    #[repr(usize)]
+    enum Enum {
+        Ten = BASE,
+        Eleven,
+    }
+
+    assert_eq!(NonZeroUsize::from(Enum::Eleven).get(), 11);
+    assert_eq!(
+        Enum::try_from(NonZeroUsize::new(10).unwrap()),
+        Ok(Enum::Ten)
+    );
+}
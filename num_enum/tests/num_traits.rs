@@ -0,0 +1,99 @@
+#![cfg(feature = "num-traits")]
+
+use ::num_traits::{FromPrimitive as _, ToPrimitive as _};
+
+// Guard against https://github.com/illicitonion/num_enum/issues/27
+mod alloc {}
+mod core {}
+mod num_enum {}
+mod std {}
+
+#[test]
+fn try_from_primitive() {
+    #[derive(Debug, Eq, PartialEq, ::num_enum::TryFromPrimitive, ::num_enum::IntoPrimitive)]
+    #[num_enum(num_traits)]
+    #[repr(u8)]
+    enum Enum {
+        Zero,
+        #[num_enum(alternatives = [3])]
+        One,
+        Two,
+    }
+
+    assert_eq!(Enum::from_i64(0), Some(Enum::Zero));
+    assert_eq!(Enum::from_u64(1), Some(Enum::One));
+    assert_eq!(Enum::from_i32(3), Some(Enum::One));
+    assert_eq!(Enum::from_u128(2), Some(Enum::Two));
+    assert_eq!(Enum::from_isize(4), None);
+    assert_eq!(Enum::from_i64(-1), None);
+    assert_eq!(Enum::from_u64(257), None);
+    assert_eq!(Enum::from_f64(2.0), Some(Enum::Two));
+
+    assert_eq!(Enum::Zero.to_i64(), Some(0));
+    assert_eq!(Enum::One.to_u8(), Some(1));
+    assert_eq!(Enum::Two.to_i128(), Some(2));
+    assert_eq!(Enum::Two.to_f32(), Some(2.0));
+}
+
+#[test]
+fn from_primitive() {
+    #[derive(Debug, Eq, PartialEq, ::num_enum::FromPrimitive)]
+    #[num_enum(num_traits)]
+    #[repr(i16)]
+    enum Enum {
+        MinusOne = -1,
+        #[num_enum(default)]
+        Other = 0,
+    }
+
+    assert_eq!(Enum::from_i8(-1), Some(Enum::MinusOne));
+    assert_eq!(Enum::from_i64(1000), Some(Enum::Other));
+    // Values which don't fit in the repr aren't passed on to the default variant.
+    assert_eq!(Enum::from_i64(100_000), None);
+    assert_eq!(Enum::from_u16(u16::MAX), None);
+}
+
+#[test]
+fn catch_all() {
+    #[derive(Debug, Eq, PartialEq, ::num_enum::FromPrimitive, ::num_enum::IntoPrimitive)]
+    #[num_enum(num_traits)]
+    #[repr(u64)]
+    enum Enum {
+        Zero = 0,
+        #[num_enum(catch_all)]
+        NonZero(u64),
+    }
+
+    assert_eq!(Enum::from_u64(0), Some(Enum::Zero));
+    assert_eq!(Enum::from_u64(u64::MAX), Some(Enum::NonZero(u64::MAX)));
+    assert_eq!(Enum::from_i8(-1), None);
+
+    assert_eq!(Enum::NonZero(u64::MAX).to_u64(), Some(u64::MAX));
+    assert_eq!(Enum::NonZero(u64::MAX).to_i64(), None);
+    assert_eq!(
+        Enum::NonZero(u64::MAX).to_i128(),
+        Some(i128::from(u64::MAX))
+    );
+    assert_eq!(Enum::NonZero(300).to_u8(), None);
+    assert_eq!(Enum::Zero.to_u8(), Some(0));
+}
+
+#[test]
+fn usable_with_num_traits_bounds() {
+    #[derive(Debug, Eq, PartialEq, ::num_enum::TryFromPrimitive, ::num_enum::IntoPrimitive)]
+    #[num_enum(num_traits)]
+    #[repr(u32)]
+    enum Enum {
+        Ten = 10,
+        Twenty = 20,
+    }
+
+    fn round_trip<T: ::num_traits::FromPrimitive + ::num_traits::ToPrimitive>(
+        value: T,
+    ) -> Option<T> {
+        T::from_u64(value.to_u64()?)
+    }
+
+    assert_eq!(round_trip(Enum::Twenty), Some(Enum::Twenty));
+    assert_eq!(round_trip(Enum::Ten), Some(Enum::Ten));
+}
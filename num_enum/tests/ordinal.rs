@@ -0,0 +1,118 @@
+use ::num_enum::Ordinal;
+
+// Guard against https://github.com/illicitonion/num_enum/issues/27
+mod alloc {}
+mod core {}
+mod num_enum {}
+mod std {}
+
+#[derive(Debug, Eq, PartialEq, Ordinal)]
+#[repr(u8)]
+enum Sparse {
+    Low = 0x10,
+    Mid = 0x20,
+    High = 0x80,
+}
+
+#[test]
+fn ordinals_follow_declaration_order() {
+    assert_eq!(Sparse::VARIANT_COUNT, 3);
+
+    assert_eq!(Sparse::Low.ordinal(), 0);
+    assert_eq!(Sparse::Mid.ordinal(), 1);
+    assert_eq!(Sparse::High.ordinal(), 2);
+
+    assert_eq!(Sparse::from_ordinal(0), Some(Sparse::Low));
+    assert_eq!(Sparse::from_ordinal(1), Some(Sparse::Mid));
+    assert_eq!(Sparse::from_ordinal(2), Some(Sparse::High));
+    assert_eq!(Sparse::from_ordinal(3), None);
+}
+
+#[test]
+fn ordinals_ignore_discriminant_order() {
+    #[derive(Debug, Eq, PartialEq, Ordinal)]
+    #[repr(i8)]
+    enum Enum {
+        Two = 2,
+        MinusOne = -1,
+        #[num_enum(alternatives = [5])]
+        Zero = 0,
+    }
+
+    assert_eq!(Enum::Two.ordinal(), 0);
+    assert_eq!(Enum::MinusOne.ordinal(), 1);
+    assert_eq!(Enum::Zero.ordinal(), 2);
+    assert_eq!(Enum::from_ordinal(1), Some(Enum::MinusOne));
+}
+
+#[test]
+fn next_and_prev() {
+    assert_eq!(Sparse::Low.next(), Some(Sparse::Mid));
+    assert_eq!(Sparse::Mid.next(), Some(Sparse::High));
+    assert_eq!(Sparse::High.next(), None);
+
+    assert_eq!(Sparse::Low.prev(), None);
+    assert_eq!(Sparse::Mid.prev(), Some(Sparse::Low));
+    assert_eq!(Sparse::High.prev(), Some(Sparse::Mid));
+}
+
+#[test]
+fn wrapping() {
+    assert_eq!(Sparse::Low.wrapping_next(), Sparse::Mid);
+    assert_eq!(Sparse::High.wrapping_next(), Sparse::Low);
+
+    assert_eq!(Sparse::Mid.wrapping_prev(), Sparse::Low);
+    assert_eq!(Sparse::Low.wrapping_prev(), Sparse::High);
+}
+
+#[test]
+fn saturating() {
+    assert_eq!(Sparse::Low.saturating_next(), Sparse::Mid);
+    assert_eq!(Sparse::High.saturating_next(), Sparse::High);
+
+    assert_eq!(Sparse::Mid.saturating_prev(), Sparse::Low);
+    assert_eq!(Sparse::Low.saturating_prev(), Sparse::Low);
+}
+
+#[test]
+fn single_variant() {
+    #[derive(Debug, Eq, PartialEq, Ordinal)]
+    #[repr(u8)]
+    enum Enum {
+        Only = 7,
+    }
+
+    assert_eq!(Enum::VARIANT_COUNT, 1);
+    assert_eq!(Enum::Only.next(), None);
+    assert_eq!(Enum::Only.prev(), None);
+    assert_eq!(Enum::Only.wrapping_next(), Enum::Only);
+    assert_eq!(Enum::Only.saturating_prev(), Enum::Only);
+}
+
+#[test]
+fn usable_in_const_contexts() {
+    const HIGH_ORDINAL: usize = Sparse::High.ordinal();
+    const SECOND: Option<Sparse> = Sparse::from_ordinal(1);
+    const WRAPPED: Sparse = Sparse::High.wrapping_next();
+
+    let per_variant = [0u32; Sparse::VARIANT_COUNT];
+
+    assert_eq!(HIGH_ORDINAL, 2);
+    assert_eq!(SECOND, Some(Sparse::Mid));
+    assert_eq!(WRAPPED, Sparse::Low);
+    assert_eq!(per_variant.len(), 3);
+}
+
+#[test]
+fn usable_through_trait() {
+    fn last<E: Ordinal>() -> Option<E> {
+        E::from_ordinal(E::VARIANT_COUNT - 1)
+    }
+
+    fn ordinal_of<E: Ordinal>(value: E) -> usize {
+        <E as Ordinal>::ordinal(value)
+    }
+
+    assert_eq!(last::<Sparse>(), Some(Sparse::High));
+    assert_eq!(ordinal_of(Sparse::Mid), 1);
+}
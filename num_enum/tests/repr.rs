@@ -0,0 +1,114 @@
+use ::std::convert::TryFrom;
+
+use ::num_enum::{
+    EnumMetadata, FromPrimitive, IntoPrimitive, TryFromPrimitive, UnsafeFromPrimitive,
+};
+
+// Guard against https://github.com/illicitonion/num_enum/issues/27
+mod alloc {}
+mod core {}
+mod num_enum {}
+mod std {}
+
+#[test]
+fn repr_c_with_integer() {
+    // rustc only allows `repr(C, u8)` for enums with fields.
+    #[derive(Debug, Eq, PartialEq, FromPrimitive, IntoPrimitive, UnsafeFromPrimitive)]
+    #[repr(C, u8)]
+    enum Enum {
+        Zero = 0,
+        One = 1,
+        #[num_enum(catch_all)]
+        Other(u8),
+    }
+
+    let one: u8 = Enum::One.into();
+    assert_eq!(one, 1);
+    let other: u8 = Enum::Other(7).into();
+    assert_eq!(other, 7);
+    assert_eq!(Enum::from(0_u8), Enum::Zero);
+    assert_eq!(Enum::from(2_u8), Enum::Other(2));
+    assert_eq!(unsafe { Enum::unchecked_transmute_from(1_u8) }, Enum::One);
+}
+
+#[test]
+fn integer_and_align_in_separate_attributes() {
+    #[derive(Debug, Eq, PartialEq, IntoPrimitive, TryFromPrimitive)]
+    #[repr(u32)]
+    #[repr(align(8))]
+    enum Enum {
+        Zero,
+        Big = 1 << 20,
+    }
+
+    assert_eq!(::core::mem::align_of::<Enum>(), 8);
+    let big: u32 = Enum::Big.into();
+    assert_eq!(big, 1 << 20);
+    assert_eq!(Enum::try_from(1_u32 << 20), Ok(Enum::Big));
+}
+
+#[test]
+fn align() {
+    #[derive(Debug, Eq, PartialEq, IntoPrimitive, TryFromPrimitive)]
+    #[repr(u8, align(4))]
+    enum Enum {
+        Zero,
+        One,
+    }
+
+    assert_eq!(::core::mem::align_of::<Enum>(), 4);
+    let one: u8 = Enum::One.into();
+    assert_eq!(one, 1);
+    assert_eq!(Enum::try_from(0_u8), Ok(Enum::Zero));
+}
+
+#[test]
+#[allow(clippy::assertions_on_constants)]
+fn bare_repr_c_uses_c_int() {
+    #[derive(Debug, Eq, PartialEq, EnumMetadata, IntoPrimitive, TryFromPrimitive)]
+    #[repr(C)]
+    enum Enum {
+        MinusOne = -1,
+        One = 1,
+    }
+
+    let minus_one: ::core::ffi::c_int = Enum::MinusOne.into();
+    assert_eq!(minus_one, -1);
+    assert_eq!(Enum::try_from(1), Ok(Enum::One));
+    assert!(Enum::try_from(0).is_err());
+
+    assert_eq!(Enum::MIN_DISCRIMINANT, -1);
+    assert_eq!(Enum::MAX_DISCRIMINANT, 1);
+    // The size of `c_int` varies between targets, so it can never be relied upon to be exhausted.
+    assert!(!Enum::IS_EXHAUSTIVE);
+}
+
+#[test]
+fn bare_repr_c_catch_all() {
+    #[derive(Debug, Eq, PartialEq, FromPrimitive, IntoPrimitive)]
+    #[repr(C)]
+    enum Enum {
+        Zero,
+        One,
+        #[num_enum(catch_all)]
+        Other(::core::ffi::c_int),
+    }
+
+    let other: ::core::ffi::c_int = Enum::Other(-3).into();
+    assert_eq!(other, -3);
+    assert_eq!(Enum::from(1), Enum::One);
+    assert_eq!(Enum::from(7), Enum::Other(7));
+}
+
+#[test]
+fn bare_repr_c_unsafe_from_primitive() {
+    #[derive(Debug, Eq, PartialEq, UnsafeFromPrimitive)]
+    #[repr(C)]
+    enum Enum {
+        Zero,
+        One,
+    }
+
+    assert_eq!(unsafe { Enum::unchecked_transmute_from(0) }, Enum::Zero);
+    assert_eq!(unsafe { Enum::unchecked_transmute_from(1) }, Enum::One);
+}
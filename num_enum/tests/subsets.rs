@@ -0,0 +1,120 @@
+use ::std::convert::TryFrom;
+
+// Guard against https://github.com/illicitonion/num_enum/issues/27
+mod alloc {}
+mod core {}
+mod num_enum {}
+mod std {}
+
+const SERVER: u16 = 500;
+
+#[derive(Debug, Eq, PartialEq, ::num_enum::Subsets)]
+#[num_enum(subset(ClientError = [BadRequest, NotFound], ServerError = [Internal, Unavailable]))]
+#[num_enum(subset(Retryable = [Unavailable]))]
+#[repr(u16)]
+#[allow(dead_code)]
+enum ErrorCode {
+    BadRequest = 400,
+    #[num_enum(alternatives = [410, 411])]
+    NotFound = 404,
+    Internal = SERVER,
+    Unavailable = SERVER + 3,
+    Teapot = 418,
+}
+
+#[test]
+fn into_superset() {
+    assert_eq!(
+        ErrorCode::from(ClientError::BadRequest),
+        ErrorCode::BadRequest
+    );
+    assert_eq!(ErrorCode::from(ClientError::NotFound), ErrorCode::NotFound);
+    assert_eq!(
+        ErrorCode::from(ServerError::Unavailable),
+        ErrorCode::Unavailable
+    );
+    assert_eq!(
+        ErrorCode::from(Retryable::Unavailable),
+        ErrorCode::Unavailable
+    );
+}
+
+#[test]
+fn from_superset() {
+    assert_eq!(
+        ClientError::try_from(ErrorCode::NotFound),
+        Ok(ClientError::NotFound)
+    );
+    assert_eq!(
+        ServerError::try_from(ErrorCode::Internal),
+        Ok(ServerError::Internal)
+    );
+
+    let err = ClientError::try_from(ErrorCode::Internal).unwrap_err();
+    assert_eq!(err.number, 500);
+    assert_eq!(
+        err.to_string(),
+        "No discriminant in enum `ClientError` matches the value `500`"
+    );
+    assert!(Retryable::try_from(ErrorCode::Teapot).is_err());
+}
+
+#[test]
+fn shares_discriminants() {
+    assert_eq!(u16::from(ClientError::NotFound), 404);
+    assert_eq!(u16::from(ServerError::Unavailable), 503);
+    assert_eq!(ServerError::try_from(500), Ok(ServerError::Internal));
+    assert!(ServerError::try_from(400).is_err());
+    assert_eq!(ClientError::try_from(411), Ok(ClientError::NotFound));
+}
+
+#[test]
+fn catch_all_superset() {
+    #[derive(Debug, Eq, PartialEq, ::num_enum::Subsets)]
+    #[num_enum(subset(Known = [Zero, One]))]
+    #[repr(u8)]
+    enum Number {
+        Zero,
+        One,
+        #[num_enum(catch_all)]
+        Other(u8),
+    }
+
+    assert_eq!(Number::from(Known::One), Number::One);
+    assert_eq!(Known::try_from(Number::Zero), Ok(Known::Zero));
+    assert_eq!(Known::try_from(Number::Other(7)).unwrap_err().number, 7);
+}
+
+#[test]
+fn whole_superset() {
+    #[derive(Debug, Eq, PartialEq, ::num_enum::Subsets)]
+    #[num_enum(subset(Same = [B, A]))]
+    #[repr(i8)]
+    enum Enum {
+        A = -1,
+        B = 1,
+    }
+
+    assert_eq!(Same::try_from(Enum::A), Ok(Same::A));
+    assert_eq!(Enum::from(Same::B), Enum::B);
+}
+
+#[test]
+fn value_attributes() {
+    #[derive(Debug, Eq, PartialEq, ::num_enum::TryFromPrimitive, ::num_enum::Subsets)]
+    #[num_enum(subset(Sub = [Masked, Skipped]))]
+    #[repr(u8)]
+    enum Enum {
+        #[num_enum(mask = 0xF0, value = 0x40)]
+        Masked = 0x40,
+        #[num_enum(skip)]
+        Skipped = 0x01,
+        Other = 0x02,
+    }
+
+    assert_eq!(Enum::try_from(0x41), Ok(Enum::Masked));
+    assert_eq!(Sub::try_from(0x41), Ok(Sub::Masked));
+    assert!(Enum::try_from(0x01).is_err());
+    assert!(Sub::try_from(0x01).is_err());
+    assert_eq!(u8::from(Sub::Skipped), 0x01);
+}
@@ -0,0 +1,164 @@
+use ::num_enum::{
+    DecodePayload, EncodePayload, PayloadError, TaggedPayload, TaggedPayloadError,
+    TryFromPrimitiveError,
+};
+use ::std::convert::TryFrom;
+
+// Guard against https://github.com/illicitonion/num_enum/issues/27
+mod alloc {}
+mod core {}
+mod num_enum {}
+mod std {}
+
+const MOVE: u8 = 5;
+
+#[derive(Debug, Eq, PartialEq, TaggedPayload)]
+#[repr(u8)]
+enum Message {
+    Ping {
+        id: u32,
+        urgent: bool,
+    } = 1,
+    Move(i16, i16) = MOVE,
+    Close,
+    #[num_enum(alternatives = [20])]
+    Raw([u8; 2]) = 10,
+}
+
+#[test]
+fn decode() {
+    assert_eq!(
+        Message::try_from((1, &[7, 0, 0, 0, 1][..])),
+        Ok(Message::Ping {
+            id: 7,
+            urgent: true
+        }),
+    );
+    assert_eq!(
+        Message::try_from((5, &[0xfe, 0xff, 0x00, 0x01][..])),
+        Ok(Message::Move(-2, 256)),
+    );
+    assert_eq!(Message::try_from((6, &[][..])), Ok(Message::Close));
+    assert_eq!(
+        Message::try_from((20, &[1, 2][..])),
+        Ok(Message::Raw([1, 2]))
+    );
+}
+
+#[test]
+fn unknown_tag() {
+    let err = Message::try_from((2, &[][..])).unwrap_err();
+    assert_eq!(
+        err,
+        TaggedPayloadError::UnknownTag(TryFromPrimitiveError::new(2))
+    );
+    assert_eq!(
+        err.to_string(),
+        "No discriminant in enum `MessageTag` matches the value `2`"
+    );
+}
+
+#[test]
+fn payload_errors() {
+    assert_eq!(
+        Message::try_from((1, &[7, 0, 0][..])),
+        Err(TaggedPayloadError::Payload(PayloadError::UnexpectedEnd)),
+    );
+    assert_eq!(
+        Message::try_from((1, &[7, 0, 0, 0, 2][..])),
+        Err(TaggedPayloadError::Payload(PayloadError::InvalidValue)),
+    );
+    assert_eq!(
+        Message::try_from((6, &[0][..])),
+        Err(TaggedPayloadError::Payload(PayloadError::TrailingBytes)),
+    );
+}
+
+#[test]
+fn tags() {
+    assert_eq!(Message::Close.tag(), MessageTag::Close);
+    assert_eq!(Message::Raw([0, 0]).tag(), MessageTag::Raw);
+    assert_eq!(u8::from(MessageTag::Move), 5);
+    assert_eq!(MessageTag::try_from(20), Ok(MessageTag::Raw));
+}
+
+#[test]
+fn round_trip() {
+    let messages = [
+        Message::Ping {
+            id: 0xdead_beef,
+            urgent: false,
+        },
+        Message::Move(i16::MIN, i16::MAX),
+        Message::Close,
+        Message::Raw([3, 4]),
+    ];
+    for message in messages {
+        let mut buf = [0; 16];
+        let (tag, len) = message.encode_tagged(&mut buf).unwrap();
+        assert_eq!(Message::try_from((tag, &buf[..len])), Ok(message));
+    }
+}
+
+#[test]
+fn encode() {
+    let mut buf = [0; 5];
+    assert_eq!(
+        Message::Ping {
+            id: 0x0403_0201,
+            urgent: true
+        }
+        .encode_tagged(&mut buf),
+        Ok((1, 5)),
+    );
+    assert_eq!(buf, [1, 2, 3, 4, 1]);
+
+    assert_eq!(Message::Close.encode_tagged(&mut []), Ok((6, 0)));
+    assert_eq!(
+        Message::Move(1, 2).encode_tagged(&mut buf[..3]),
+        Err(PayloadError::BufferTooSmall),
+    );
+}
+
+#[test]
+fn custom_field_type() {
+    #[derive(Debug, Eq, PartialEq)]
+    struct Celsius(i8);
+
+    impl DecodePayload for Celsius {
+        fn decode_payload(payload: &[u8]) -> Result<(Self, &[u8]), PayloadError> {
+            let (value, rest) = i8::decode_payload(payload)?;
+            if value < -100 {
+                return Err(PayloadError::InvalidValue);
+            }
+            Ok((Celsius(value), rest))
+        }
+    }
+
+    impl EncodePayload for Celsius {
+        fn encode_payload(&self, buf: &mut [u8]) -> Result<usize, PayloadError> {
+            self.0.encode_payload(buf)
+        }
+    }
+
+    #[derive(Debug, Eq, PartialEq, TaggedPayload)]
+    #[repr(u16)]
+    enum Reading {
+        Temperature(Celsius) = 0x100,
+    }
+
+    assert_eq!(
+        Reading::try_from((0x100, &[0xf6][..])),
+        Ok(Reading::Temperature(Celsius(-10))),
+    );
+    assert_eq!(
+        Reading::try_from((0x100, &[0x80][..])),
+        Err(TaggedPayloadError::Payload(PayloadError::InvalidValue)),
+    );
+
+    let mut buf = [0; 1];
+    assert_eq!(
+        Reading::Temperature(Celsius(3)).encode_tagged(&mut buf),
+        Ok((0x100, 1))
+    );
+}
@@ -0,0 +1,9 @@
+#[derive(Debug, Eq, PartialEq, num_enum::FromPrimitive)]
+#[repr(u8)]
+enum Enum {
+    Zero = 0,
+    #[num_enum(catch_all)]
+    NonZero { value: core::primitive::u16 },
+}
+
+fn main() {}
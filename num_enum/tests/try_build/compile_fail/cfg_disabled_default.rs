@@ -0,0 +1,10 @@
+#[derive(num_enum::FromPrimitive)]
+#[repr(u8)]
+enum Numbers {
+    Zero,
+    #[cfg(any())]
+    #[num_enum(default)]
+    Other,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+#[derive(num_enum::CodeTables)]
+#[repr(u8)]
+enum Codec {
+    #[num_enum(code(rtp = 0))]
+    Mulaw,
+    #[num_enum(code(rtp = [96, 0]))]
+    Aac,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+#[derive(num_enum::CodeTables)]
+#[repr(u8)]
+enum Codec {
+    #[num_enum(code(rtp = 0, mp4 = 0x6D))]
+    Mulaw,
+    #[num_enum(code(mp4 = 0x61))]
+    Aac,
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+#[derive(num_enum::IntoPrimitive)]
+#[repr(u8)]
+enum WireKind {
+    Request,
+    Legacy,
+}
+
+#[derive(num_enum::IntoPrimitive)]
+#[num_enum(convert(to = WireKind, by = "value"))]
+#[repr(u8)]
+enum Kind {
+    Request,
+    #[num_enum(maps_to = OtherKind::Legacy)]
+    OldRequest,
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+#[derive(num_enum::IntoPrimitive)]
+#[repr(u8)]
+enum WireKind {
+    Request,
+    Response,
+}
+
+#[derive(num_enum::IntoPrimitive)]
+#[num_enum(convert(to = WireKind, by = "name"))]
+#[repr(u8)]
+enum Kind {
+    Request,
+    Response,
+    Cancel,
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+#[derive(num_enum::TryFromPrimitive)]
+#[repr(u8)]
+enum WireKind {
+    Request = 1,
+    Response = 2,
+}
+
+#[derive(num_enum::IntoPrimitive)]
+#[num_enum(convert(to = WireKind, by = "value"))]
+#[repr(u8)]
+enum Kind {
+    Request = 1,
+    Response,
+    Cancel,
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+#[derive(num_enum::TryFromPrimitive)]
+#[repr(u8)]
+enum WireKind {
+    Request = 1,
+    Response = 2,
+}
+
+#[derive(num_enum::TryFromPrimitive)]
+#[num_enum(convert(to = WireKind, by = "value"))]
+#[repr(u8)]
+enum Kind {
+    Request = 1,
+    Response = 2,
+}
+
+fn main() {}
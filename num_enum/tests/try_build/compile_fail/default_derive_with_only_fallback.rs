@@ -0,0 +1,9 @@
+#[derive(Debug, Eq, PartialEq, num_enum::Default)]
+#[repr(u8)]
+enum Enum {
+    Zero = 0,
+    #[num_enum(fallback)]
+    Other = 1,
+}
+
+fn main() {}
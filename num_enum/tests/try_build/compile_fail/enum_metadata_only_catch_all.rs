@@ -0,0 +1,8 @@
+#[derive(num_enum::EnumMetadata)]
+#[repr(u8)]
+enum Enum {
+    #[num_enum(catch_all)]
+    Other(u8),
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+#[derive(Debug, Eq, PartialEq, num_enum::FromPrimitive)]
+#[repr(u8)]
+enum Enum {
+    #[num_enum(fallback)]
+    Zero = 0,
+    #[num_enum(catch_all)]
+    NonZero(u8),
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+#[derive(Debug, Eq, PartialEq, num_enum::FromPrimitive)]
+#[repr(u8)]
+enum Enum {
+    Zero = 0,
+    #[default]
+    #[num_enum(fallback)]
+    Other = 1,
+}
+
+fn main() {}
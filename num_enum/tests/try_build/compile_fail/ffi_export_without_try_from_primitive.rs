@@ -0,0 +1,10 @@
+#[derive(num_enum::FromPrimitive)]
+#[num_enum(ffi_export(prefix = "opcode"))]
+#[repr(u8)]
+enum Opcode {
+    Nop,
+    #[num_enum(default)]
+    Unknown,
+}
+
+fn main() {}
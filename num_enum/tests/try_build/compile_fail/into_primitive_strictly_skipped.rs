@@ -0,0 +1,9 @@
+#[derive(num_enum::IntoPrimitive)]
+#[repr(u8)]
+enum Enum {
+    Zero,
+    #[num_enum(skip(strict))]
+    Invalid,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+#[derive(num_enum::TryFromPrimitive)]
+#[repr(u8)]
+enum Opcode {
+    Nop = 0,
+    #[num_enum(alternatives = [0x41], mask = 0xF0, value = 0x40)]
+    Load = 0x40,
+}
+
+fn main() {}
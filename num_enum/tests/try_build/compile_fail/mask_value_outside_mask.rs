@@ -0,0 +1,8 @@
+#[derive(num_enum::TryFromPrimitive)]
+#[repr(u8)]
+enum Opcode {
+    #[num_enum(mask = 0xF0, value = 0x41)]
+    Load = 0x40,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+#[derive(num_enum::FromPrimitive)]
+#[repr(u8)]
+enum Numbers {
+    Zero,
+    #[num_enum(fallback)]
+    One,
+    #[num_enum(fallback)]
+    Two,
+}
+
+fn main() {
+
+}
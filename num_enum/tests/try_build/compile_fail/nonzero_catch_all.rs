@@ -0,0 +1,10 @@
+#[derive(num_enum::FromPrimitive)]
+#[num_enum(nonzero)]
+#[repr(u8)]
+enum Enum {
+    One = 1,
+    #[num_enum(catch_all)]
+    Other(u8),
+}
+
+fn main() {}
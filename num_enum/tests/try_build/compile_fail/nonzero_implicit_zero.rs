@@ -0,0 +1,9 @@
+#[derive(num_enum::IntoPrimitive)]
+#[num_enum(nonzero)]
+#[repr(u8)]
+enum Enum {
+    Zero,
+    One,
+}
+
+fn main() {}
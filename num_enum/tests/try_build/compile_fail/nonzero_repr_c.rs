@@ -0,0 +1,8 @@
+#[derive(num_enum::IntoPrimitive)]
+#[num_enum(nonzero)]
+#[repr(C)]
+enum Enum {
+    One = 1,
+}
+
+fn main() {}
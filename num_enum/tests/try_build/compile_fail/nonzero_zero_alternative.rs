@@ -0,0 +1,10 @@
+#[derive(num_enum::TryFromPrimitive)]
+#[num_enum(nonzero)]
+#[repr(u8)]
+enum Enum {
+    One = 1,
+    #[num_enum(alternatives = [3, 0])]
+    Two = 2,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+const ONE: u8 = 1;
+
+#[derive(num_enum::IntoPrimitive)]
+#[num_enum(nonzero)]
+#[repr(u8)]
+enum Enum {
+    One = ONE,
+    Zero = ONE - 1,
+}
+
+fn main() {}
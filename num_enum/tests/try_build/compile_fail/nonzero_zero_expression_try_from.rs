@@ -0,0 +1,11 @@
+const ZERO: u8 = 0;
+
+#[derive(num_enum::TryFromPrimitive)]
+#[num_enum(nonzero)]
+#[repr(u8)]
+enum Enum {
+    One = 1,
+    Zero = ZERO,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+#[derive(num_enum::Ordinal)]
+#[repr(u8)]
+enum Enum {
+    Zero = 0,
+    #[num_enum(catch_all)]
+    Other(u8),
+}
+
+fn main() {}
@@ -0,0 +1,5 @@
+#[derive(num_enum::Ordinal)]
+#[repr(u8)]
+enum Empty {}
+
+fn main() {}
@@ -0,0 +1,9 @@
+#[derive(num_enum::IntoPrimitive)]
+#[repr(u8)]
+#[repr(u16)]
+enum Numbers {
+    Zero,
+    One,
+}
+
+fn main() {}
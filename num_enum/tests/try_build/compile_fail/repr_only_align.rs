@@ -1,5 +1,5 @@
 #[derive(num_enum::IntoPrimitive)]
-#[repr(C)]
+#[repr(align(4))]
 enum Numbers {
     Zero,
     One,
@@ -0,0 +1,11 @@
+#[derive(num_enum::FromPrimitive)]
+#[repr(u8)]
+enum Message {
+    Hello,
+    #[num_enum(since = 3)]
+    Resume,
+    #[num_enum(default)]
+    Unknown,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+#[derive(num_enum::FromPrimitive)]
+#[repr(u8)]
+enum Enum {
+    Zero,
+    #[num_enum(default, skip)]
+    Invalid,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+#[derive(num_enum::TryFromPrimitive)]
+#[repr(u8)]
+enum Enum {
+    Zero,
+    #[num_enum(skip, alternatives = [5])]
+    Invalid,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+#[derive(num_enum::Subsets)]
+#[num_enum(subset(Known = [Zero, Other]))]
+#[repr(u8)]
+enum Number {
+    Zero,
+    #[num_enum(catch_all)]
+    Other(u8),
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+#[derive(num_enum::Subsets)]
+#[num_enum(subset(ClientError = [BadRequest, Gone]))]
+#[repr(u16)]
+enum ErrorCode {
+    BadRequest = 400,
+    NotFound = 404,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+#[derive(num_enum::TryFromPrimitive, num_enum::Subsets)]
+#[num_enum(subset(Current = [Hello, Resume]))]
+#[repr(u8)]
+enum Message {
+    Hello,
+    #[num_enum(since = 3)]
+    Resume,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+#[derive(num_enum::IntoPrimitive)]
+#[num_enum(subset(Small = [Zero, One]))]
+#[repr(u8)]
+enum Number {
+    Zero,
+    One,
+    Two,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+#[derive(num_enum::TaggedPayload)]
+#[repr(u8)]
+enum Message {
+    Ping { id: u32 } = 1,
+    #[num_enum(catch_all)]
+    Other(u8),
+}
+
+fn main() {}
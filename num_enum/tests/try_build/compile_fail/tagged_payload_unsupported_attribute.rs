@@ -0,0 +1,9 @@
+#[derive(num_enum::TaggedPayload)]
+#[repr(u8)]
+enum Message {
+    Ping { id: u32 } = 1,
+    #[num_enum(alternatives = [3], since = 2)]
+    Pong { id: u32 } = 2,
+}
+
+fn main() {}
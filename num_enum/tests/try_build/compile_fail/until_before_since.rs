@@ -0,0 +1,9 @@
+#[derive(num_enum::TryFromPrimitive)]
+#[repr(u8)]
+enum Message {
+    Hello,
+    #[num_enum(until = 2, since = 3)]
+    Resume,
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+#[derive(num_enum::ValueConstants)]
+#[repr(u8)]
+enum Method {
+    HttpGet,
+    HTTPGet,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+#[derive(num_enum::IntoPrimitive)]
+#[num_enum(value_eq)]
+#[repr(u8)]
+enum Message {
+    Ping { id: u32 } = 1,
+    Close = 2,
+}
+
+fn main() {}
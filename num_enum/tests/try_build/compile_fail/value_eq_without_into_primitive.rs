@@ -0,0 +1,10 @@
+#[derive(num_enum::FromPrimitive)]
+#[num_enum(value_eq)]
+#[repr(u8)]
+enum Number {
+    Zero,
+    #[num_enum(catch_all)]
+    Other(u8),
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+#[derive(num_enum::IntoPrimitive)]
+#[num_enum(value_module = opcodes)]
+#[repr(u8)]
+enum Opcode {
+    Add,
+    Sub,
+}
+
+fn main() {}
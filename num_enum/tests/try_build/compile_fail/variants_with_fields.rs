@@ -1,4 +1,4 @@
-use num_enum::{FromPrimitive, IntoPrimitive, TryFromPrimitive};
+use num_enum::{FromPrimitive, TryFromPrimitive, UnsafeFromPrimitive};
 
 #[derive(Debug, Eq, PartialEq, TryFromPrimitive)]
 #[repr(u8)]
@@ -13,7 +13,7 @@ enum Colour {
     Red { intensity: u8 },
 }
 
-#[derive(Debug, Eq, PartialEq, IntoPrimitive)]
+#[derive(Debug, Eq, PartialEq, UnsafeFromPrimitive)]
 #[repr(u8)]
 enum Meaningless {
     Beep(),
@@ -196,6 +196,127 @@ fn discriminant_expressions() {
     assert_eq!(eight, Ok(Enum::Eight));
 }
 
+#[test]
+fn skipped_variant() {
+    #[derive(Debug, Eq, PartialEq, TryFromPrimitive, ::num_enum::IntoPrimitive)]
+    #[repr(u8)]
+    enum Enum {
+        Zero,
+        #[num_enum(skip)]
+        Invalid,
+        Two,
+    }
+
+    let zero: Result<Enum, _> = 0u8.try_into();
+    assert_eq!(zero, Ok(Enum::Zero));
+
+    let invalid: Result<Enum, _> = 1u8.try_into();
+    assert_eq!(
+        invalid.unwrap_err().to_string(),
+        "No discriminant in enum `Enum` matches the value `1`",
+    );
+
+    let two: Result<Enum, _> = 2u8.try_into();
+    assert_eq!(two, Ok(Enum::Two));
+
+    assert_eq!(u8::from(Enum::Invalid), 1);
+}
+
+#[test]
+fn masked_values() {
+    #[derive(Debug, Eq, PartialEq, TryFromPrimitive)]
+    #[repr(u8)]
+    enum Opcode {
+        Nop = 0x00,
+        #[num_enum(mask = 0xF0, value = 0x40)]
+        Load = 0x40,
+        #[num_enum(mask = 0b1100_0011, value = 0b1000_0001)]
+        Store = 0x81,
+        Halt = 0xFF,
+    }
+
+    assert_eq!(Opcode::try_from(0x00), Ok(Opcode::Nop));
+    assert_eq!(Opcode::try_from(0x40), Ok(Opcode::Load));
+    assert_eq!(Opcode::try_from(0x4F), Ok(Opcode::Load));
+    assert!(Opcode::try_from(0x50).is_err());
+    assert_eq!(Opcode::try_from(0x81), Ok(Opcode::Store));
+    assert_eq!(Opcode::try_from(0x85), Ok(Opcode::Store));
+    assert_eq!(Opcode::try_from(0xBD), Ok(Opcode::Store));
+    assert!(Opcode::try_from(0x82).is_err());
+    assert!(Opcode::try_from(0xC1).is_err());
+    assert_eq!(Opcode::try_from(0xFF), Ok(Opcode::Halt));
+}
+
+#[test]
+fn masked_values_signed() {
+    #[derive(Debug, Eq, PartialEq, TryFromPrimitive)]
+    #[repr(i8)]
+    enum Enum {
+        Zero = 0,
+        // Masks are bit patterns of the repr, so `0xF0` is allowed for `i8`.
+        #[num_enum(mask = 0xF0, value = 0xF0)]
+        SmallNegative = -1,
+    }
+
+    assert_eq!(Enum::try_from(0), Ok(Enum::Zero));
+    assert_eq!(Enum::try_from(-1), Ok(Enum::SmallNegative));
+    assert_eq!(Enum::try_from(-16), Ok(Enum::SmallNegative));
+    assert!(Enum::try_from(-17).is_err());
+    assert!(Enum::try_from(1).is_err());
+}
+
+#[test]
+fn versioned_variants() {
+    use ::num_enum::{TryFromPrimitiveError, TryFromPrimitiveVersionedError};
+
+    #[derive(Debug, Eq, PartialEq, TryFromPrimitive)]
+    #[repr(u8)]
+    enum Message {
+        Hello,
+        #[num_enum(since = 3)]
+        Resume,
+        #[num_enum(until = 4)]
+        LegacyPing,
+        #[num_enum(since = 2, until = 5)]
+        Batch,
+    }
+
+    assert!(Message::Hello.is_valid_in(0));
+    assert!(!Message::Resume.is_valid_in(2));
+    assert!(Message::Resume.is_valid_in(3));
+    assert!(Message::LegacyPing.is_valid_in(4));
+    assert!(!Message::LegacyPing.is_valid_in(5));
+    assert!(!Message::Batch.is_valid_in(1));
+    assert!(Message::Batch.is_valid_in(5));
+    assert!(!Message::Batch.is_valid_in(6));
+
+    assert_eq!(
+        Message::try_from_primitive_versioned(1, 3),
+        Ok(Message::Resume)
+    );
+    let err = Message::try_from_primitive_versioned(1, 2).unwrap_err();
+    assert_eq!(
+        err,
+        TryFromPrimitiveVersionedError::NotInVersion {
+            number: 1,
+            version: 2
+        }
+    );
+    assert_eq!(
+        err.to_string(),
+        "The value `1` of enum `Message` isn't valid in version 2"
+    );
+    assert_eq!(
+        Message::try_from_primitive_versioned(4, 2),
+        Err(TryFromPrimitiveVersionedError::Unknown(
+            TryFromPrimitiveError::new(4)
+        ))
+    );
+
+    // The plain conversion accepts variants from every version.
+    assert_eq!(Message::try_from(1), Ok(Message::Resume));
+}
+
 #[cfg(feature = "complex-expressions")]
 mod complex {
     use num_enum::TryFromPrimitive;
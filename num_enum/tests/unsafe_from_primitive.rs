@@ -22,7 +22,7 @@ fn has_unsafe_from_primitive_number() {
 }
 
 #[test]
-fn has_unsafe_from_primitive_number_with_alternatives_and_default_which_are_ignored() {
+fn has_unsafe_from_primitive_number_with_alternatives_and_default_which_is_ignored() {
     #[derive(Debug, Eq, PartialEq, UnsafeFromPrimitive)]
     #[repr(u8)]
     enum Enum {
@@ -38,6 +38,8 @@ fn has_unsafe_from_primitive_number_with_alternatives_and_default_which_are_igno
         assert_eq!(Enum::unchecked_transmute_from(0_u8), Enum::Zero);
         assert_eq!(Enum::unchecked_transmute_from(1_u8), Enum::One);
         assert_eq!(Enum::unchecked_transmute_from(2_u8), Enum::Some);
+        assert_eq!(Enum::unchecked_transmute_from(3_u8), Enum::Some);
+        assert_eq!(Enum::unchecked_transmute_from(4_u8), Enum::Some);
         assert_eq!(Enum::unchecked_transmute_from(5_u8), Enum::Many);
         // Any other conversions would be undefined behavior.
     }
@@ -52,7 +54,7 @@ fn has_unsafe_from_primitive_number_with_alternatives_and_default_which_are_igno
 }
 
 #[test]
-fn has_unsafe_from_primitive_number_with_alternatives_and_std_default_which_are_ignored() {
+fn has_unsafe_from_primitive_number_with_alternatives_and_std_default_which_is_ignored() {
     #[derive(Debug, Default, Eq, PartialEq, UnsafeFromPrimitive)]
     #[repr(u8)]
     enum Enum {
@@ -68,6 +70,8 @@ fn has_unsafe_from_primitive_number_with_alternatives_and_std_default_which_are_
         assert_eq!(Enum::unchecked_transmute_from(0_u8), Enum::Zero);
         assert_eq!(Enum::unchecked_transmute_from(1_u8), Enum::One);
         assert_eq!(Enum::unchecked_transmute_from(2_u8), Enum::Some);
+        assert_eq!(Enum::unchecked_transmute_from(3_u8), Enum::Some);
+        assert_eq!(Enum::unchecked_transmute_from(4_u8), Enum::Some);
         assert_eq!(Enum::unchecked_transmute_from(5_u8), Enum::Many);
         // Any other conversions would be undefined behavior.
     }
@@ -80,3 +84,66 @@ fn has_unsafe_from_primitive_number_with_alternatives_and_std_default_which_are_
         assert_eq!(Enum::from_unchecked(5_u8), Enum::Many);
     }
 }
+
+#[test]
+fn has_unsafe_from_primitive_number_with_catch_all() {
+    #[derive(Debug, Eq, PartialEq, UnsafeFromPrimitive)]
+    #[repr(u8)]
+    enum Enum {
+        Zero = 0,
+        #[num_enum(catch_all)]
+        NonZero(u8) = 1,
+        #[num_enum(alternatives = [3])]
+        Two = 2,
+    }
+
+    unsafe {
+        assert_eq!(Enum::unchecked_transmute_from(0_u8), Enum::Zero);
+        assert_eq!(Enum::unchecked_transmute_from(1_u8), Enum::NonZero(1));
+        assert_eq!(Enum::unchecked_transmute_from(2_u8), Enum::Two);
+        assert_eq!(Enum::unchecked_transmute_from(3_u8), Enum::Two);
+        // Every value is sound to convert when there is a catch-all variant.
+        assert_eq!(Enum::unchecked_transmute_from(255_u8), Enum::NonZero(255));
+    }
+}
+
+#[test]
+fn has_unsafe_from_primitive_number_with_alternatives_and_discriminant_expressions() {
+    const ONE: u8 = 1;
+
+    #[derive(Debug, Eq, PartialEq, UnsafeFromPrimitive)]
+    #[repr(u8)]
+    enum Enum {
+        Zero,
+        #[num_enum(alternatives = [10])]
+        One = ONE,
+        Two,
+    }
+
+    unsafe {
+        assert_eq!(Enum::unchecked_transmute_from(0_u8), Enum::Zero);
+        assert_eq!(Enum::unchecked_transmute_from(1_u8), Enum::One);
+        assert_eq!(Enum::unchecked_transmute_from(10_u8), Enum::One);
+        assert_eq!(Enum::unchecked_transmute_from(2_u8), Enum::Two);
+    }
+}
+
+#[test]
+fn has_unsafe_from_primitive_number_with_masks() {
+    #[derive(Debug, Eq, PartialEq, UnsafeFromPrimitive)]
+    #[repr(u8)]
+    enum Enum {
+        Zero,
+        #[num_enum(skip)]
+        One,
+        #[num_enum(mask = 0xF0, value = 0x10)]
+        Sixteen = 0x10,
+    }
+
+    unsafe {
+        assert_eq!(Enum::unchecked_transmute_from(0_u8), Enum::Zero);
+        assert_eq!(Enum::unchecked_transmute_from(1_u8), Enum::One);
+        assert_eq!(Enum::unchecked_transmute_from(0x10_u8), Enum::Sixteen);
+        assert_eq!(Enum::unchecked_transmute_from(0x1F_u8), Enum::Sixteen);
+    }
+}
@@ -0,0 +1,75 @@
+// Guard against https://github.com/illicitonion/num_enum/issues/27
+mod alloc {}
+mod core {}
+mod num_enum {}
+mod std {}
+
+const BASE: u8 = 0x10;
+
+#[derive(::num_enum::ValueConstants)]
+#[num_enum(value_module = opcodes)]
+#[repr(u8)]
+#[allow(dead_code)]
+enum Opcode {
+    Nop,
+    Add = BASE,
+    AddImmediate,
+    #[num_enum(alternatives = [0x21])]
+    HTTPGet = 0x20,
+    Utf8Text = BASE * 3,
+}
+
+#[test]
+fn associated_constants() {
+    assert_eq!(Opcode::NOP_VALUE, 0);
+    assert_eq!(Opcode::ADD_VALUE, 0x10);
+    assert_eq!(Opcode::ADD_IMMEDIATE_VALUE, 0x11);
+    assert_eq!(Opcode::HTTP_GET_VALUE, 0x20);
+    assert_eq!(Opcode::UTF8_TEXT_VALUE, 0x30);
+}
+
+#[test]
+fn module_constants() {
+    assert_eq!(opcodes::NOP, 0);
+    assert_eq!(opcodes::ADD, 0x10);
+    assert_eq!(opcodes::ADD_IMMEDIATE, 0x11);
+    assert_eq!(opcodes::HTTP_GET, 0x20);
+    assert_eq!(opcodes::UTF8_TEXT, 0x30);
+}
+
+#[test]
+fn usable_as_patterns() {
+    fn describe(byte: u8) -> &'static str {
+        match byte {
+            Opcode::NOP_VALUE => "nop",
+            Opcode::ADD_VALUE => "add",
+            opcodes::ADD_IMMEDIATE => "add immediate",
+            Opcode::HTTP_GET_VALUE => "get",
+            _ => "unknown",
+        }
+    }
+
+    assert_eq!(describe(0), "nop");
+    assert_eq!(describe(0x11), "add immediate");
+    assert_eq!(describe(0x20), "get");
+    // Alternatives don't get constants of their own.
+    assert_eq!(describe(0x21), "unknown");
+}
+
+#[test]
+fn signed_and_catch_all() {
+    #[derive(::num_enum::ValueConstants)]
+    #[repr(i16)]
+    #[allow(dead_code)]
+    enum Enum {
+        MinusOne = -1,
+        Zero,
+        r#Type = 7,
+        #[num_enum(catch_all)]
+        Other(i16),
+    }
+
+    assert_eq!(Enum::MINUS_ONE_VALUE, -1i16);
+    assert_eq!(Enum::ZERO_VALUE, 0i16);
+    assert_eq!(Enum::TYPE_VALUE, 7i16);
+}
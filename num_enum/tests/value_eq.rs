@@ -0,0 +1,69 @@
+use ::num_enum::{FromPrimitive, IntoPrimitive};
+use ::std::collections::{BTreeSet, HashMap};
+
+// Guard against https://github.com/illicitonion/num_enum/issues/27
+mod alloc {}
+mod core {}
+mod num_enum {}
+mod std {}
+
+#[derive(Debug, Clone, Copy, IntoPrimitive, FromPrimitive)]
+#[num_enum(value_eq)]
+#[repr(u8)]
+enum Enum {
+    Zero = 0,
+    Three = 3,
+    #[num_enum(catch_all)]
+    Unknown(u8),
+}
+
+#[test]
+fn eq() {
+    assert_eq!(Enum::Unknown(3), Enum::Three);
+    assert_eq!(Enum::Three, Enum::Unknown(3));
+    assert_eq!(Enum::Unknown(4), Enum::Unknown(4));
+    assert_ne!(Enum::Unknown(4), Enum::Three);
+    assert_ne!(Enum::Zero, Enum::Three);
+}
+
+#[test]
+fn ord() {
+    assert!(Enum::Zero < Enum::Unknown(1));
+    assert!(Enum::Unknown(2) < Enum::Three);
+    assert!(Enum::Unknown(4) > Enum::Three);
+    assert_eq!(
+        Enum::Unknown(3).cmp(&Enum::Three),
+        ::std::cmp::Ordering::Equal
+    );
+
+    let set: BTreeSet<Enum> = [Enum::Unknown(3), Enum::Three, Enum::Zero]
+        .into_iter()
+        .collect();
+    assert_eq!(set.len(), 2);
+}
+
+#[test]
+fn hash() {
+    let mut map = HashMap::new();
+    map.insert(Enum::Three, "three");
+    map.insert(Enum::Unknown(3), "also three");
+    map.insert(Enum::Unknown(7), "seven");
+
+    assert_eq!(map.len(), 2);
+    assert_eq!(map[&Enum::Three], "also three");
+    assert_eq!(map[&Enum::Unknown(7)], "seven");
+}
+
+#[test]
+fn without_catch_all() {
+    #[derive(Debug, IntoPrimitive)]
+    #[num_enum(value_eq)]
+    #[repr(i8)]
+    enum Enum {
+        MinusOne = -1,
+        One = 1,
+    }
+
+    assert_eq!(Enum::One, Enum::One);
+    assert!(Enum::MinusOne < Enum::One);
+}
@@ -1,14 +1,28 @@
 use crate::utils::die;
 use proc_macro2::Span;
+use quote::ToTokens;
 use syn::{
     parse::{Parse, ParseStream},
     Error, Result,
 };
 
 mod kw {
+    syn::custom_keyword!(by);
     syn::custom_keyword!(constructor);
+    syn::custom_keyword!(convert);
+    syn::custom_keyword!(deprecated_value_warning);
     syn::custom_keyword!(error_type);
+    syn::custom_keyword!(fallible);
+    syn::custom_keyword!(ffi_export);
     syn::custom_keyword!(name);
+    syn::custom_keyword!(nonzero);
+    syn::custom_keyword!(num_traits);
+    syn::custom_keyword!(on_unknown);
+    syn::custom_keyword!(prefix);
+    syn::custom_keyword!(subset);
+    syn::custom_keyword!(to);
+    syn::custom_keyword!(value_eq);
+    syn::custom_keyword!(value_module);
 }
 
 // Example: error_type(name = Foo, constructor = Foo::new)
@@ -17,6 +31,15 @@ mod kw {
 pub(crate) struct Attributes {
     pub(crate) error_type: Option<ErrorTypeAttribute>,
     pub(crate) crate_path: Option<CrateAttribute>,
+    pub(crate) num_traits: Option<NumTraitsAttribute>,
+    pub(crate) deprecated_value_warning: Option<DeprecatedValueWarningAttribute>,
+    pub(crate) on_unknown: Option<OnUnknownAttribute>,
+    pub(crate) value_eq: Option<ValueEqAttribute>,
+    pub(crate) nonzero: Option<NonzeroAttribute>,
+    pub(crate) value_module: Option<ValueModuleAttribute>,
+    pub(crate) convert: Vec<ConvertAttribute>,
+    pub(crate) subsets: Vec<SubsetDefinition>,
+    pub(crate) ffi_export: Option<FfiExportAttribute>,
 }
 
 // Example: error_type(name = Foo, constructor = Foo::new)
@@ -24,6 +47,15 @@ pub(crate) struct Attributes {
 pub(crate) enum AttributeItem {
     ErrorType(ErrorTypeAttribute),
     CratePath(CrateAttribute),
+    NumTraits(NumTraitsAttribute),
+    DeprecatedValueWarning(DeprecatedValueWarningAttribute),
+    OnUnknown(OnUnknownAttribute),
+    ValueEq(ValueEqAttribute),
+    Nonzero(NonzeroAttribute),
+    ValueModule(ValueModuleAttribute),
+    Convert(ConvertAttribute),
+    Subset(SubsetAttribute),
+    FfiExport(FfiExportAttribute),
 }
 
 impl Attributes {
@@ -48,6 +80,97 @@ impl Attributes {
         } else {
             self.error_type = other.error_type;
         }
+        if self.num_traits.is_some() {
+            if let Some(other) = &other.num_traits {
+                return Err(Error::new(
+                    other.span,
+                    "num_enum attribute must have at most one num_traits",
+                ));
+            }
+        } else {
+            self.num_traits = other.num_traits;
+        }
+        if self.deprecated_value_warning.is_some() {
+            if let Some(other) = &other.deprecated_value_warning {
+                return Err(Error::new(
+                    other.span,
+                    "num_enum attribute must have at most one deprecated_value_warning",
+                ));
+            }
+        } else {
+            self.deprecated_value_warning = other.deprecated_value_warning;
+        }
+        if self.on_unknown.is_some() {
+            if let Some(other) = &other.on_unknown {
+                return Err(Error::new(
+                    other.span,
+                    "num_enum attribute must have at most one on_unknown",
+                ));
+            }
+        } else {
+            self.on_unknown = other.on_unknown;
+        }
+        if self.value_eq.is_some() {
+            if let Some(other) = &other.value_eq {
+                return Err(Error::new(
+                    other.span,
+                    "num_enum attribute must have at most one value_eq",
+                ));
+            }
+        } else {
+            self.value_eq = other.value_eq;
+        }
+        if self.nonzero.is_some() {
+            if let Some(other) = &other.nonzero {
+                return Err(Error::new(
+                    other.span,
+                    "num_enum attribute must have at most one nonzero",
+                ));
+            }
+        } else {
+            self.nonzero = other.nonzero;
+        }
+        if self.value_module.is_some() {
+            if let Some(other) = &other.value_module {
+                return Err(Error::new(
+                    other.span,
+                    "num_enum attribute must have at most one value_module",
+                ));
+            }
+        } else {
+            self.value_module = other.value_module;
+        }
+        if self.ffi_export.is_some() {
+            if let Some(other) = &other.ffi_export {
+                return Err(Error::new(
+                    other.span,
+                    "num_enum attribute must have at most one ffi_export",
+                ));
+            }
+        } else {
+            self.ffi_export = other.ffi_export;
+        }
+        // An enum may be converted into several others, but only once into each.
+        for convert in other.convert {
+            if self
+                .convert
+                .iter()
+                .any(|existing| existing.to == convert.to)
+            {
+                return Err(convert.duplicate_error());
+            }
+            self.convert.push(convert);
+        }
+        for subset in other.subsets {
+            if self
+                .subsets
+                .iter()
+                .any(|existing| existing.name == subset.name)
+            {
+                return Err(subset.duplicate_error());
+            }
+            self.subsets.push(subset);
+        }
         Ok(())
     }
 }
@@ -57,6 +180,15 @@ impl Parse for Attributes {
         let attribute_items = input.parse_terminated(AttributeItem::parse, syn::Token![,])?;
         let mut maybe_error_type = None;
         let mut maybe_krate_path = None;
+        let mut maybe_num_traits = None;
+        let mut maybe_deprecated_value_warning = None;
+        let mut maybe_on_unknown = None;
+        let mut maybe_value_eq = None;
+        let mut maybe_nonzero = None;
+        let mut maybe_value_module = None;
+        let mut convert = vec![];
+        let mut subsets: Vec<SubsetDefinition> = vec![];
+        let mut maybe_ffi_export = None;
         for attribute_item in &attribute_items {
             match attribute_item {
                 AttributeItem::ErrorType(error_type) => {
@@ -77,11 +209,100 @@ impl Parse for Attributes {
                     }
                     maybe_krate_path = Some(krate_path.clone());
                 }
+                AttributeItem::NumTraits(num_traits) => {
+                    if maybe_num_traits.is_some() {
+                        return Err(Error::new(
+                            num_traits.span,
+                            "num_enum attribute must have at most one num_traits",
+                        ));
+                    }
+                    maybe_num_traits = Some(num_traits.clone());
+                }
+                AttributeItem::DeprecatedValueWarning(deprecated_value_warning) => {
+                    if maybe_deprecated_value_warning.is_some() {
+                        return Err(Error::new(
+                            deprecated_value_warning.span,
+                            "num_enum attribute must have at most one deprecated_value_warning",
+                        ));
+                    }
+                    maybe_deprecated_value_warning = Some(deprecated_value_warning.clone());
+                }
+                AttributeItem::OnUnknown(on_unknown) => {
+                    if maybe_on_unknown.is_some() {
+                        return Err(Error::new(
+                            on_unknown.span,
+                            "num_enum attribute must have at most one on_unknown",
+                        ));
+                    }
+                    maybe_on_unknown = Some(on_unknown.clone());
+                }
+                AttributeItem::ValueEq(value_eq) => {
+                    if maybe_value_eq.is_some() {
+                        return Err(Error::new(
+                            value_eq.span,
+                            "num_enum attribute must have at most one value_eq",
+                        ));
+                    }
+                    maybe_value_eq = Some(value_eq.clone());
+                }
+                AttributeItem::Nonzero(nonzero) => {
+                    if maybe_nonzero.is_some() {
+                        return Err(Error::new(
+                            nonzero.span,
+                            "num_enum attribute must have at most one nonzero",
+                        ));
+                    }
+                    maybe_nonzero = Some(nonzero.clone());
+                }
+                AttributeItem::ValueModule(value_module) => {
+                    if maybe_value_module.is_some() {
+                        return Err(Error::new(
+                            value_module.span,
+                            "num_enum attribute must have at most one value_module",
+                        ));
+                    }
+                    maybe_value_module = Some(value_module.clone());
+                }
+                AttributeItem::Convert(convert_attr) => {
+                    if convert
+                        .iter()
+                        .any(|existing: &ConvertAttribute| existing.to == convert_attr.to)
+                    {
+                        return Err(convert_attr.duplicate_error());
+                    }
+                    convert.push(convert_attr.clone());
+                }
+                AttributeItem::Subset(subset_attr) => {
+                    for subset in &subset_attr.subsets {
+                        if subsets.iter().any(|existing| existing.name == subset.name) {
+                            return Err(subset.duplicate_error());
+                        }
+                        subsets.push(subset.clone());
+                    }
+                }
+                AttributeItem::FfiExport(ffi_export) => {
+                    if maybe_ffi_export.is_some() {
+                        return Err(Error::new(
+                            ffi_export.span,
+                            "num_enum attribute must have at most one ffi_export",
+                        ));
+                    }
+                    maybe_ffi_export = Some(ffi_export.clone());
+                }
             }
         }
         Ok(Self {
             error_type: maybe_error_type,
             crate_path: maybe_krate_path,
+            num_traits: maybe_num_traits,
+            deprecated_value_warning: maybe_deprecated_value_warning,
+            on_unknown: maybe_on_unknown,
+            value_eq: maybe_value_eq,
+            nonzero: maybe_nonzero,
+            value_module: maybe_value_module,
+            convert,
+            subsets,
+            ffi_export: maybe_ffi_export,
         })
     }
 }
@@ -93,6 +314,24 @@ impl Parse for AttributeItem {
             input.parse().map(Self::ErrorType)
         } else if lookahead.peek(syn::token::Crate) {
             input.parse().map(Self::CratePath)
+        } else if lookahead.peek(kw::num_traits) {
+            input.parse().map(Self::NumTraits)
+        } else if lookahead.peek(kw::deprecated_value_warning) {
+            input.parse().map(Self::DeprecatedValueWarning)
+        } else if lookahead.peek(kw::on_unknown) {
+            input.parse().map(Self::OnUnknown)
+        } else if lookahead.peek(kw::value_eq) {
+            input.parse().map(Self::ValueEq)
+        } else if lookahead.peek(kw::nonzero) {
+            input.parse().map(Self::Nonzero)
+        } else if lookahead.peek(kw::value_module) {
+            input.parse().map(Self::ValueModule)
+        } else if lookahead.peek(kw::convert) {
+            input.parse().map(Self::Convert)
+        } else if lookahead.peek(kw::subset) {
+            input.parse().map(Self::Subset)
+        } else if lookahead.peek(kw::ffi_export) {
+            input.parse().map(Self::FfiExport)
         } else {
             Err(lookahead.error())
         }
@@ -228,9 +467,301 @@ impl Parse for CrateAttribute {
     }
 }
 
+// Example: num_traits
+#[derive(Clone)]
+#[cfg_attr(test, derive(Debug))]
+pub(crate) struct NumTraitsAttribute {
+    span: Span,
+}
+
+impl Parse for NumTraitsAttribute {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let keyword: kw::num_traits = input.parse()?;
+        Ok(Self { span: keyword.span })
+    }
+}
+
+// Examples:
+//  * deprecated_value_warning
+//  * deprecated_value_warning = on_deprecated_value
+#[derive(Clone)]
+#[cfg_attr(test, derive(Debug))]
+pub(crate) struct DeprecatedValueWarningAttribute {
+    /// Called with the variant's name and the value instead of logging a warning, if present.
+    pub(crate) hook: Option<syn::Path>,
+
+    span: Span,
+}
+
+impl Parse for DeprecatedValueWarningAttribute {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let keyword: kw::deprecated_value_warning = input.parse()?;
+        let hook = if input.peek(syn::Token![=]) {
+            input.parse::<syn::Token![=]>()?;
+            Some(input.parse()?)
+        } else {
+            None
+        };
+        Ok(Self {
+            hook,
+            span: keyword.span,
+        })
+    }
+}
+
+// Example: on_unknown = report_unknown
+#[derive(Clone)]
+#[cfg_attr(test, derive(Debug))]
+pub(crate) struct OnUnknownAttribute {
+    pub(crate) path: syn::Path,
+
+    pub(crate) span: Span,
+}
+
+impl Parse for OnUnknownAttribute {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let keyword: kw::on_unknown = input.parse()?;
+        input.parse::<syn::Token![=]>()?;
+        let path = input.parse()?;
+        Ok(Self {
+            path,
+            span: keyword.span,
+        })
+    }
+}
+
+// Example: value_eq
+#[derive(Clone)]
+#[cfg_attr(test, derive(Debug))]
+pub(crate) struct ValueEqAttribute {
+    pub(crate) span: Span,
+}
+
+impl Parse for ValueEqAttribute {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let keyword: kw::value_eq = input.parse()?;
+        Ok(Self { span: keyword.span })
+    }
+}
+
+// Example: nonzero
+#[derive(Clone)]
+#[cfg_attr(test, derive(Debug))]
+pub(crate) struct NonzeroAttribute {
+    pub(crate) span: Span,
+}
+
+impl Parse for NonzeroAttribute {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let keyword: kw::nonzero = input.parse()?;
+        Ok(Self { span: keyword.span })
+    }
+}
+
+// Example: value_module = opcodes
+#[derive(Clone)]
+#[cfg_attr(test, derive(Debug))]
+pub(crate) struct ValueModuleAttribute {
+    pub(crate) ident: syn::Ident,
+
+    pub(crate) span: Span,
+}
+
+impl Parse for ValueModuleAttribute {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let keyword: kw::value_module = input.parse()?;
+        input.parse::<syn::Token![=]>()?;
+        let ident = input.parse()?;
+        Ok(Self {
+            ident,
+            span: keyword.span,
+        })
+    }
+}
+
+// Example: convert(to = WireKind, by = "name")
+#[derive(Clone)]
+#[cfg_attr(test, derive(Debug))]
+pub(crate) struct ConvertAttribute {
+    pub(crate) to: syn::Path,
+    pub(crate) by: ConvertBy,
+    /// Whether converting by value implements `TryFrom` even if every variant has a counterpart.
+    pub(crate) fallible: bool,
+
+    pub(crate) span: Span,
+}
+
+/// How variants are matched up by a `convert` attribute.
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(test, derive(Debug))]
+pub(crate) enum ConvertBy {
+    /// Through the primitive value, with `TryFromPrimitive` on the target.
+    Value,
+    /// To the target's variant with the same name.
+    Name,
+}
+
+impl ConvertAttribute {
+    fn duplicate_error(&self) -> Error {
+        Error::new(
+            self.span,
+            format!(
+                "num_enum attribute must have at most one convert into `{}`",
+                self.to.to_token_stream()
+            ),
+        )
+    }
+}
+
+impl Parse for ConvertAttribute {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let keyword: kw::convert = input.parse()?;
+        let span = keyword.span;
+        let content;
+        syn::parenthesized!(content in input);
+        let mut to = None;
+        let mut by = None;
+        let mut fallible = None;
+        while !content.is_empty() {
+            let lookahead = content.lookahead1();
+            if lookahead.peek(kw::to) {
+                if to.is_some() {
+                    die!("num_enum convert attribute must have exactly one `to` value");
+                }
+                content.parse::<kw::to>()?;
+                content.parse::<syn::Token![=]>()?;
+                to = Some(content.parse()?);
+            } else if lookahead.peek(kw::by) {
+                if by.is_some() {
+                    die!("num_enum convert attribute must have exactly one `by` value");
+                }
+                content.parse::<kw::by>()?;
+                content.parse::<syn::Token![=]>()?;
+                let by_str: syn::LitStr = content.parse()?;
+                by = Some(match by_str.value().as_str() {
+                    "value" => ConvertBy::Value,
+                    "name" => ConvertBy::Name,
+                    _ => {
+                        die!(by_str => "num_enum convert attribute's `by` must be \"value\" or \"name\"")
+                    }
+                });
+            } else if lookahead.peek(kw::fallible) {
+                let keyword: kw::fallible = content.parse()?;
+                if fallible.is_some() {
+                    die!(keyword => "num_enum convert attribute must have at most one `fallible`");
+                }
+                fallible = Some(keyword);
+            } else {
+                return Err(lookahead.error());
+            }
+            if !content.is_empty() {
+                content.parse::<syn::Token![,]>()?;
+            }
+        }
+        if let (Some(keyword), Some(ConvertBy::Name)) = (&fallible, by) {
+            die!(keyword => "num_enum convert attribute's `fallible` requires `by = \"value\"`");
+        }
+        match (to, by) {
+            (Some(to), Some(by)) => Ok(Self {
+                to,
+                by,
+                fallible: fallible.is_some(),
+                span,
+            }),
+            _ => Err(Error::new(
+                span,
+                "num_enum convert attribute requires `to` and `by` values",
+            )),
+        }
+    }
+}
+
+// Example: subset(ClientError = [BadRequest, NotFound], ServerError = [Internal])
+#[derive(Clone)]
+#[cfg_attr(test, derive(Debug))]
+pub(crate) struct SubsetAttribute {
+    pub(crate) subsets: syn::punctuated::Punctuated<SubsetDefinition, syn::Token![,]>,
+}
+
+impl Parse for SubsetAttribute {
+    fn parse(input: ParseStream) -> Result<Self> {
+        input.parse::<kw::subset>()?;
+        let content;
+        syn::parenthesized!(content in input);
+        Ok(Self {
+            subsets: content.parse_terminated(SubsetDefinition::parse, syn::Token![,])?,
+        })
+    }
+}
+
+// Example: ClientError = [BadRequest, NotFound]
+#[derive(Clone)]
+#[cfg_attr(test, derive(Debug))]
+pub(crate) struct SubsetDefinition {
+    pub(crate) name: syn::Ident,
+    pub(crate) variants: Vec<syn::Ident>,
+}
+
+impl SubsetDefinition {
+    fn duplicate_error(&self) -> Error {
+        Error::new(
+            self.name.span(),
+            format!(
+                "num_enum attribute must have at most one subset named `{}`",
+                self.name
+            ),
+        )
+    }
+}
+
+impl Parse for SubsetDefinition {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let name = input.parse()?;
+        input.parse::<syn::Token![=]>()?;
+        let content;
+        syn::bracketed!(content in input);
+        let variants: syn::punctuated::Punctuated<syn::Ident, syn::Token![,]> =
+            content.parse_terminated(syn::Ident::parse, syn::Token![,])?;
+        Ok(Self {
+            name,
+            variants: variants.into_iter().collect(),
+        })
+    }
+}
+
+// Example: ffi_export(prefix = "opcode")
+#[derive(Clone)]
+#[cfg_attr(test, derive(Debug))]
+pub(crate) struct FfiExportAttribute {
+    pub(crate) prefix: syn::LitStr,
+
+    pub(crate) span: Span,
+}
+
+impl Parse for FfiExportAttribute {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let keyword: kw::ffi_export = input.parse()?;
+        let content;
+        syn::parenthesized!(content in input);
+        content.parse::<kw::prefix>()?;
+        content.parse::<syn::Token![=]>()?;
+        let prefix: syn::LitStr = content.parse()?;
+        if syn::parse_str::<syn::Ident>(&format!("{}_is_valid", prefix.value())).is_err() {
+            die!(&prefix => format!(
+                "`{}` can't be used as the prefix of a function name",
+                prefix.value()
+            ));
+        }
+        Ok(Self {
+            prefix,
+            span: keyword.span,
+        })
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use crate::enum_attributes::Attributes;
+    use crate::enum_attributes::{Attributes, ConvertBy};
     use quote::ToTokens;
     use syn::{parse_quote, Path};
 
@@ -280,6 +811,202 @@ mod test {
         );
     }
 
+    #[test]
+    fn parse_num_traits_attr() {
+        let attributes: Attributes = syn::parse_str("num_traits, crate = ::num_enum").unwrap();
+        assert!(attributes.num_traits.is_some());
+        assert!(attributes.crate_path.is_some());
+
+        let attributes: Attributes = syn::parse_str("crate = ::num_enum").unwrap();
+        assert!(attributes.num_traits.is_none());
+    }
+
+    #[test]
+    fn multiple_num_traits() {
+        let err = syn::parse_str::<Attributes>("num_traits, num_traits").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "num_enum attribute must have at most one num_traits"
+        );
+    }
+
+    #[test]
+    fn parse_deprecated_value_warning_attr() {
+        let attributes: Attributes = syn::parse_str("deprecated_value_warning").unwrap();
+        assert!(attributes.deprecated_value_warning.unwrap().hook.is_none());
+
+        let expected_hook: Path = parse_quote! { crate::on_deprecated };
+        let attributes: Attributes =
+            syn::parse_str("deprecated_value_warning = crate::on_deprecated").unwrap();
+        assert_eq!(
+            attributes
+                .deprecated_value_warning
+                .unwrap()
+                .hook
+                .to_token_stream()
+                .to_string(),
+            expected_hook.to_token_stream().to_string()
+        );
+    }
+
+    #[test]
+    fn multiple_deprecated_value_warnings() {
+        let err =
+            syn::parse_str::<Attributes>("deprecated_value_warning, deprecated_value_warning")
+                .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "num_enum attribute must have at most one deprecated_value_warning"
+        );
+    }
+
+    #[test]
+    fn parse_on_unknown_attr() {
+        let expected_path: Path = parse_quote! { telemetry::unknown_value };
+        let attributes: Attributes =
+            syn::parse_str("on_unknown = telemetry::unknown_value").unwrap();
+        assert_eq!(
+            attributes
+                .on_unknown
+                .unwrap()
+                .path
+                .to_token_stream()
+                .to_string(),
+            expected_path.to_token_stream().to_string()
+        );
+
+        let err = syn::parse_str::<Attributes>("on_unknown").unwrap_err();
+        assert_eq!(err.to_string(), "expected `=`");
+    }
+
+    #[test]
+    fn parse_value_eq_attr() {
+        let attributes: Attributes = syn::parse_str("value_eq").unwrap();
+        assert!(attributes.value_eq.is_some());
+
+        let err = syn::parse_str::<Attributes>("value_eq, value_eq").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "num_enum attribute must have at most one value_eq"
+        );
+    }
+
+    #[test]
+    fn parse_value_module_attr() {
+        let attributes: Attributes = syn::parse_str("value_module = opcodes").unwrap();
+        assert_eq!(attributes.value_module.unwrap().ident, "opcodes");
+
+        let err = syn::parse_str::<Attributes>("value_module = a, value_module = b").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "num_enum attribute must have at most one value_module"
+        );
+    }
+
+    #[test]
+    fn parse_convert_attr() {
+        let attributes: Attributes = syn::parse_str(
+            r#"convert(to = wire::Kind, by = "name"), convert(by = "value", to = Other)"#,
+        )
+        .unwrap();
+        assert_eq!(attributes.convert.len(), 2);
+        assert_eq!(
+            attributes.convert[0].to.to_token_stream().to_string(),
+            "wire :: Kind"
+        );
+        assert_eq!(attributes.convert[0].by, ConvertBy::Name);
+        assert_eq!(attributes.convert[1].by, ConvertBy::Value);
+        assert!(!attributes.convert[1].fallible);
+
+        let err =
+            syn::parse_str::<Attributes>(r#"convert(to = Other, by = "ordinal")"#).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "num_enum convert attribute's `by` must be \"value\" or \"name\""
+        );
+
+        let err = syn::parse_str::<Attributes>(
+            r#"convert(to = Other, by = "name"), convert(to = Other, by = "value")"#,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "num_enum attribute must have at most one convert into `Other`"
+        );
+
+        let err = syn::parse_str::<Attributes>("convert(to = Other)").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "num_enum convert attribute requires `to` and `by` values"
+        );
+
+        let attributes: Attributes =
+            syn::parse_str(r#"convert(to = Other, by = "value", fallible)"#).unwrap();
+        assert!(attributes.convert[0].fallible);
+
+        let err = syn::parse_str::<Attributes>(r#"convert(to = Other, by = "name", fallible)"#)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "num_enum convert attribute's `fallible` requires `by = \"value\"`"
+        );
+    }
+
+    #[test]
+    fn parse_subset_attr() {
+        let attributes: Attributes = syn::parse_str(
+            "subset(ClientError = [BadRequest, NotFound], ServerError = [Internal]), subset(Empty = [])",
+        )
+        .unwrap();
+        let subsets: Vec<(String, Vec<String>)> = attributes
+            .subsets
+            .iter()
+            .map(|subset| {
+                (
+                    subset.name.to_string(),
+                    subset.variants.iter().map(|v| v.to_string()).collect(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            subsets,
+            [
+                (
+                    "ClientError".to_owned(),
+                    vec!["BadRequest".to_owned(), "NotFound".to_owned()]
+                ),
+                ("ServerError".to_owned(), vec!["Internal".to_owned()]),
+                ("Empty".to_owned(), vec![]),
+            ]
+        );
+
+        let err = syn::parse_str::<Attributes>("subset(A = [X]), subset(A = [Y])").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "num_enum attribute must have at most one subset named `A`"
+        );
+    }
+
+    #[test]
+    fn parse_ffi_export_attr() {
+        let attributes: Attributes = syn::parse_str(r#"ffi_export(prefix = "opcode")"#).unwrap();
+        assert_eq!(attributes.ffi_export.unwrap().prefix.value(), "opcode");
+
+        let err = syn::parse_str::<Attributes>(r#"ffi_export(prefix = "op-code")"#).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "`op-code` can't be used as the prefix of a function name"
+        );
+
+        let err =
+            syn::parse_str::<Attributes>(r#"ffi_export(prefix = "a"), ffi_export(prefix = "b")"#)
+                .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "num_enum attribute must have at most one ffi_export"
+        );
+    }
+
     #[test]
     fn missing_constructor() {
         let err = syn::parse_str::<Attributes>("error_type(name = Foo)").unwrap_err();
@@ -5,12 +5,13 @@ extern crate proc_macro;
 
 use proc_macro::TokenStream;
 use proc_macro2::Span;
-use quote::quote;
-use syn::{parse_macro_input, Expr, Ident};
+use quote::{format_ident, quote, quote_spanned, ToTokens};
+use syn::{spanned::Spanned, Expr, Ident};
 
 mod enum_attributes;
 mod parsing;
-use parsing::{get_crate_path, EnumInfo};
+use enum_attributes::ConvertBy;
+use parsing::{enum_of_variant_path, get_crate_path, Derive, EnumInfo};
 mod utils;
 mod variant_attributes;
 
@@ -33,25 +34,107 @@ mod variant_attributes;
 /// let zero: u8 = Number::Zero.into();
 /// assert_eq!(zero, 0u8);
 /// ```
+///
+/// ## Allows reading the discriminant of an enum with fields.
+///
+/// `From<&Enum>` is also implemented for such enums, as they often aren't `Copy`.
+///
+/// ```rust
+/// use num_enum::IntoPrimitive;
+///
+/// #[derive(IntoPrimitive)]
+/// #[repr(u8)]
+/// enum Message {
+///     Ping { id: u32 } = 1,
+///     Close = 10,
+/// }
+///
+/// let message = Message::Ping { id: 7 };
+/// let opcode: u8 = (&message).into();
+/// assert_eq!(opcode, 1u8);
+/// ```
 #[proc_macro_derive(IntoPrimitive, attributes(num_enum, catch_all))]
 pub fn derive_into_primitive(input: TokenStream) -> TokenStream {
-    let enum_info = parse_macro_input!(input as EnumInfo);
-    let catch_all = enum_info.catch_all();
+    expand(input, into_primitive)
+}
+
+fn into_primitive(enum_info: EnumInfo) -> syn::Result<proc_macro2::TokenStream> {
+    let krate = get_crate_path(enum_info.crate_path.clone());
     let name = &enum_info.name;
     let repr = &enum_info.repr;
 
-    let body = if let Some(catch_all_ident) = catch_all {
+    if let Some(skipped_ident) = enum_info.strictly_skipped() {
+        let message = format!(
+            "#[derive(num_enum::IntoPrimitive)] can't convert `{}::{}` into a primitive, as it is marked `#[num_enum(skip(strict))]`",
+            name, skipped_ident
+        );
+        return Err(syn::Error::new_spanned(skipped_ident, message));
+    }
+
+    // Enums with fields can't be cast with `as`, even if they have explicit discriminants.
+    let body = if enum_info.has_catch_all() || enum_info.has_non_unit_variants() {
+        primitive_value_of_ref(&enum_info, quote! { &enum_value })
+    } else {
+        quote! { enum_value as Self }
+    };
+
+    // Enums with fields often aren't `Copy`, so also allow reading the discriminant of a borrow.
+    let from_ref_impl = if enum_info.has_non_unit_variants() {
+        let body = primitive_value_of_ref(&enum_info, quote! { enum_value });
         quote! {
-            match enum_value {
-                #name::#catch_all_ident(raw) => raw,
-                rest => unsafe { *(&rest as *const #name as *const Self) }
+            #[allow(deprecated)]
+            impl From<&#name> for #repr {
+                #[inline]
+                fn from (enum_value: &#name) -> Self
+                {
+                    #body
+                }
             }
         }
     } else {
-        quote! { enum_value as Self }
+        quote! {}
+    };
+
+    let num_traits_impl = if enum_info.num_traits {
+        num_traits_to_primitive(&enum_info, &krate)
+    } else {
+        quote! {}
+    };
+
+    let nonzero_impl = match &enum_info.nonzero {
+        Some(nonzero) => {
+            let assertions = nonzero_assertions(&enum_info);
+            quote! {
+                #assertions
+
+                #[allow(deprecated)]
+                impl From<#name> for ::core::num::#nonzero {
+                    #[inline]
+                    fn from (enum_value: #name) -> Self
+                    {
+                        match ::core::num::#nonzero::new(::core::convert::From::from(enum_value)) {
+                            ::core::option::Option::Some(number) => number,
+                            ::core::option::Option::None => unreachable!("non-zero enum"),
+                        }
+                    }
+                }
+            }
+        }
+        None => quote! {},
+    };
+
+    let value_eq_impls = if enum_info.value_eq {
+        // Comparing only the discriminants of variants with fields would ignore the fields.
+        enum_info.require_unit_variants()?;
+        value_eq_impls(&enum_info)
+    } else {
+        quote! {}
     };
 
-    TokenStream::from(quote! {
+    let convert_impls = convert_impls(&enum_info, &krate)?;
+
+    Ok(quote! {
+        #[allow(deprecated)]
         impl From<#name> for #repr {
             #[inline]
             fn from (enum_value: #name) -> Self
@@ -59,6 +142,16 @@ pub fn derive_into_primitive(input: TokenStream) -> TokenStream {
                 #body
             }
         }
+
+        #from_ref_impl
+
+        #num_traits_impl
+
+        #nonzero_impl
+
+        #value_eq_impls
+
+        #convert_impls
     })
 }
 
@@ -89,45 +182,136 @@ pub fn derive_into_primitive(input: TokenStream) -> TokenStream {
 /// ```
 #[proc_macro_derive(FromPrimitive, attributes(num_enum, default, catch_all))]
 pub fn derive_from_primitive(input: TokenStream) -> TokenStream {
-    let enum_info: EnumInfo = parse_macro_input!(input);
+    expand(input, from_primitive)
+}
+
+fn from_primitive(enum_info: EnumInfo) -> syn::Result<proc_macro2::TokenStream> {
+    enum_info.require_unit_variants()?;
     let krate = get_crate_path(enum_info.crate_path.clone());
 
     let is_naturally_exhaustive = enum_info.is_naturally_exhaustive();
     let catch_all_body = match is_naturally_exhaustive {
         Ok(is_naturally_exhaustive) => {
-            if is_naturally_exhaustive {
-                quote! { unreachable!("exhaustive enum") }
-            } else if let Some(default_ident) = enum_info.default() {
-                quote! { Self::#default_ident }
-            } else if let Some(catch_all_ident) = enum_info.catch_all() {
-                quote! { Self::#catch_all_ident(number) }
+            let fallback = if is_naturally_exhaustive {
+                None
+            } else if let Some(fallback_ident) = enum_info.fallback() {
+                Some(quote! { Self::#fallback_ident })
+            } else if let Some(catch_all) = catch_all_from_number(&enum_info) {
+                Some(catch_all)
             } else {
                 let span = Span::call_site();
                 let message =
-                    "#[derive(num_enum::FromPrimitive)] requires enum to be exhaustive, or a variant marked with `#[default]`, `#[num_enum(default)]`, or `#[num_enum(catch_all)`";
-                return syn::Error::new(span, message).to_compile_error().into();
+                    "#[derive(num_enum::FromPrimitive)] requires enum to be exhaustive, or a variant marked with `#[default]`, `#[num_enum(default)]`, `#[num_enum(fallback)]`, or `#[num_enum(catch_all)`";
+                return Err(syn::Error::new(span, message));
+            };
+            match fallback {
+                Some(fallback) => {
+                    let on_unknown = enum_info.on_unknown.as_ref().map(|on_unknown| {
+                        let name = &enum_info.name;
+                        quote! { #on_unknown(stringify!(#name), number); }
+                    });
+                    quote! {
+                        {
+                            #on_unknown
+                            (#fallback, true)
+                        }
+                    }
+                }
+                None => quote! { unreachable!("exhaustive enum") },
             }
         }
         Err(err) => {
-            return err.to_compile_error().into();
+            return Err(err);
         }
     };
 
     let EnumInfo {
-        ref name, ref repr, ..
+        ref name,
+        ref vis,
+        ref repr,
+        ..
     } = enum_info;
 
-    let variant_idents: Vec<Ident> = enum_info.variant_idents();
-    let expression_idents: Vec<Vec<Ident>> = enum_info.expression_idents();
-    let variant_expressions: Vec<Vec<Expr>> = enum_info.variant_expressions();
+    let variant_idents: Vec<Ident> = enum_info.decoded_variant_idents();
+    let expression_idents: Vec<Vec<Ident>> = enum_info.decoded_expression_idents();
+    let variant_expressions: Vec<Vec<Expr>> = enum_info.decoded_variant_expressions();
+
+    let nonzero_impl = enum_info.nonzero.as_ref().map(|nonzero| {
+        let assertions = nonzero_assertions(&enum_info);
+        quote! {
+            #assertions
+
+            #[allow(deprecated)]
+            impl ::core::convert::From<::core::num::#nonzero> for #name {
+                #[inline]
+                fn from (
+                    number: ::core::num::#nonzero,
+                ) -> Self {
+                    #krate::FromPrimitive::from_primitive(number.get())
+                }
+            }
+        }
+    });
+
+    // Only a catch-all variant can hold a value which another variant should be used for.
+    let normalize_impl = if enum_info.has_catch_all() {
+        let value = primitive_value_of_ref(&enum_info, quote! { &self });
+        quote! {
+            #[allow(deprecated)]
+            impl #name {
+                /// Converts this variant's primitive value back into a variant, so that a
+                /// catch-all variant holding the discriminant of another variant becomes that
+                /// variant.
+                #[inline]
+                #vis fn normalize(self) -> Self {
+                    <Self as #krate::FromPrimitive>::from_primitive(#value)
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let deprecated_value_warnings = deprecated_value_warnings(&enum_info, &krate);
+    let arm_bodies: Vec<proc_macro2::TokenStream> = variant_idents
+        .iter()
+        .zip(&deprecated_value_warnings)
+        .map(|(variant_ident, deprecated_value_warning)| {
+            quote! {
+                {
+                    #deprecated_value_warning
+                    (Self::#variant_ident, false)
+                }
+            }
+        })
+        .collect();
+    let mask_arms = mask_arms(&enum_info, &variant_idents, &arm_bodies);
 
     debug_assert_eq!(variant_idents.len(), variant_expressions.len());
 
-    TokenStream::from(quote! {
+    let num_traits_impl = if enum_info.num_traits {
+        num_traits_from_primitive(
+            &enum_info,
+            &krate,
+            quote! {
+                ::core::option::Option::Some(#krate::FromPrimitive::from_primitive(number))
+            },
+        )
+    } else {
+        quote! {}
+    };
+
+    Ok(quote! {
+        #[allow(deprecated)]
         impl #krate::FromPrimitive for #name {
             type Primitive = #repr;
 
+            #[inline]
             fn from_primitive(number: Self::Primitive) -> Self {
+                <Self as #krate::FromPrimitive>::from_primitive_reporting(number).0
+            }
+
+            fn from_primitive_reporting(number: Self::Primitive) -> (Self, bool) {
                 // Use intermediate const(s) so that enums defined like
                 // `Two = ONE + 1u8` work properly.
                 #![allow(non_upper_case_globals)]
@@ -139,15 +323,16 @@ pub fn derive_from_primitive(input: TokenStream) -> TokenStream {
                 #[deny(unreachable_patterns)]
                 match number {
                     #(
-                        #( #expression_idents )|*
-                        => Self::#variant_idents,
+                        #( #expression_idents )|* => #arm_bodies,
                     )*
+                    #( #mask_arms )*
                     #[allow(unreachable_patterns)]
                     _ => #catch_all_body,
                 }
             }
         }
 
+        #[allow(deprecated)]
         impl ::core::convert::From<#repr> for #name {
             #[inline]
             fn from (
@@ -157,7 +342,14 @@ pub fn derive_from_primitive(input: TokenStream) -> TokenStream {
             }
         }
 
+        #num_traits_impl
+
+        #nonzero_impl
+
+        #normalize_impl
+
         #[doc(hidden)]
+        #[allow(deprecated)]
         impl #krate::CannotDeriveBothFromPrimitiveAndTryFromPrimitive for #name {}
     })
 }
@@ -189,25 +381,92 @@ pub fn derive_from_primitive(input: TokenStream) -> TokenStream {
 /// ```
 #[proc_macro_derive(TryFromPrimitive, attributes(num_enum))]
 pub fn derive_try_from_primitive(input: TokenStream) -> TokenStream {
-    let enum_info: EnumInfo = parse_macro_input!(input);
+    expand(input, try_from_primitive)
+}
+
+fn try_from_primitive(enum_info: EnumInfo) -> syn::Result<proc_macro2::TokenStream> {
+    enum_info.require_unit_variants()?;
     let krate = get_crate_path(enum_info.crate_path.clone());
     let EnumInfo {
         ref name,
+        ref vis,
         ref repr,
         ref error_type_info,
         ..
     } = enum_info;
 
-    let variant_idents: Vec<Ident> = enum_info.variant_idents();
-    let expression_idents: Vec<Vec<Ident>> = enum_info.expression_idents();
-    let variant_expressions: Vec<Vec<Expr>> = enum_info.variant_expressions();
+    let variant_idents: Vec<Ident> = enum_info.decoded_variant_idents();
+    let expression_idents: Vec<Vec<Ident>> = enum_info.decoded_expression_idents();
+    let variant_expressions: Vec<Vec<Expr>> = enum_info.decoded_variant_expressions();
 
     debug_assert_eq!(variant_idents.len(), variant_expressions.len());
 
+    let deprecated_value_warnings = deprecated_value_warnings(&enum_info, &krate);
+
+    let arm_bodies: Vec<proc_macro2::TokenStream> = variant_idents
+        .iter()
+        .zip(&deprecated_value_warnings)
+        .map(|(variant_ident, deprecated_value_warning)| {
+            quote! {
+                {
+                    #deprecated_value_warning
+                    ::core::result::Result::Ok(Self::#variant_ident)
+                }
+            }
+        })
+        .collect();
+    let is_valid_bodies = vec![quote! { true }; variant_idents.len()];
+    let is_valid_mask_arms = mask_arms(&enum_info, &variant_idents, &is_valid_bodies);
+    let mask_arms = mask_arms(&enum_info, &variant_idents, &arm_bodies);
+
     let error_type = &error_type_info.name;
     let error_constructor = &error_type_info.constructor;
 
-    TokenStream::from(quote! {
+    let nonzero_impl = enum_info.nonzero.as_ref().map(|nonzero| {
+        let assertions = nonzero_assertions(&enum_info);
+        quote! {
+            #assertions
+
+            #[allow(deprecated)]
+            impl ::core::convert::TryFrom<::core::num::#nonzero> for #name {
+                type Error = #error_type;
+
+                #[inline]
+                fn try_from (
+                    number: ::core::num::#nonzero,
+                ) -> ::core::result::Result<Self, #error_type>
+                {
+                    #krate::TryFromPrimitive::try_from_primitive(number.get())
+                }
+            }
+        }
+    });
+
+    let num_traits_impl = if enum_info.num_traits {
+        num_traits_from_primitive(
+            &enum_info,
+            &krate,
+            quote! {
+                #krate::TryFromPrimitive::try_from_primitive(number).ok()
+            },
+        )
+    } else {
+        quote! {}
+    };
+
+    let versioned_impl = if enum_info.has_versioned_variants() {
+        versioned_impl(&enum_info, &krate)
+    } else {
+        quote! {}
+    };
+
+    let ffi_export_fns = match &enum_info.ffi_prefix {
+        Some(prefix) => ffi_export_fns(&enum_info, &krate, prefix),
+        None => quote! {},
+    };
+
+    Ok(quote! {
+        #[allow(deprecated)]
         impl #krate::TryFromPrimitive for #name {
             type Primitive = #repr;
             type Error = #error_type;
@@ -231,9 +490,9 @@ pub fn derive_try_from_primitive(input: TokenStream) -> TokenStream {
                 #[deny(unreachable_patterns)]
                 match number {
                     #(
-                        #( #expression_idents )|*
-                        => ::core::result::Result::Ok(Self::#variant_idents),
+                        #( #expression_idents )|* => #arm_bodies,
                     )*
+                    #( #mask_arms )*
                     #[allow(unreachable_patterns)]
                     _ => ::core::result::Result::Err(
                         #error_constructor ( number )
@@ -242,6 +501,7 @@ pub fn derive_try_from_primitive(input: TokenStream) -> TokenStream {
             }
         }
 
+        #[allow(deprecated)]
         impl ::core::convert::TryFrom<#repr> for #name {
             type Error = #error_type;
 
@@ -254,7 +514,41 @@ pub fn derive_try_from_primitive(input: TokenStream) -> TokenStream {
             }
         }
 
+        #[allow(deprecated)]
+        impl #name {
+            // Whether `try_from_primitive` would succeed, in a const context, so that enums which
+            // are converted into this one by value can check every value has a counterpart.
+            #[doc(hidden)]
+            #[inline]
+            #vis const fn __num_enum_is_valid_primitive(number: #repr) -> bool {
+                #![allow(non_upper_case_globals)]
+                #(
+                    #(
+                        const #expression_idents: #repr = #variant_expressions;
+                    )*
+                )*
+                #[deny(unreachable_patterns)]
+                match number {
+                    #(
+                        #( #expression_idents )|* => true,
+                    )*
+                    #( #is_valid_mask_arms )*
+                    #[allow(unreachable_patterns)]
+                    _ => false,
+                }
+            }
+        }
+
+        #num_traits_impl
+
+        #nonzero_impl
+
+        #versioned_impl
+
+        #ffi_export_fns
+
         #[doc(hidden)]
+        #[allow(deprecated)]
         impl #krate::CannotDeriveBothFromPrimitiveAndTryFromPrimitive for #name {}
     })
 }
@@ -270,7 +564,11 @@ pub fn derive_try_from_primitive(input: TokenStream) -> TokenStream {
 /// bottleneck for you, you should avoid doing this, as the unsafe code has potential to cause serious memory issues in
 /// your program.
 ///
-/// Note that this derive ignores any `default`, `catch_all`, and `alternatives` attributes on the enum.
+/// If any variant has `alternatives`, or is marked `catch_all`, the conversion is done with a `match` rather than a
+/// transmute, so alternatives map to their variant, and values not covered by any other variant produce the
+/// `catch_all` variant.
+///
+/// Note that this derive ignores any `default` attributes on the enum.
 /// If you need support for conversions from these values, you should use `TryFromPrimitive` or `FromPrimitive`.
 ///
 /// ```rust
@@ -299,20 +597,65 @@ pub fn derive_try_from_primitive(input: TokenStream) -> TokenStream {
 /// }
 /// ```
 #[proc_macro_derive(UnsafeFromPrimitive, attributes(num_enum))]
-pub fn derive_unsafe_from_primitive(stream: TokenStream) -> TokenStream {
-    let enum_info = parse_macro_input!(stream as EnumInfo);
-    let krate = get_crate_path(enum_info.crate_path);
+pub fn derive_unsafe_from_primitive(input: TokenStream) -> TokenStream {
+    expand(input, unsafe_from_primitive)
+}
+
+fn unsafe_from_primitive(enum_info: EnumInfo) -> syn::Result<proc_macro2::TokenStream> {
+    enum_info.require_unit_variants()?;
+    let krate = get_crate_path(enum_info.crate_path.clone());
 
     let EnumInfo {
         ref name, ref repr, ..
     } = enum_info;
 
-    TokenStream::from(quote! {
+    // Transmuting is only sound if every value which may be passed in is the discriminant of a
+    // unit variant, so anything else needs to go through a match.
+    let body = if enum_info.has_catch_all() || enum_info.has_alternatives() {
+        let variant_idents: Vec<Ident> = enum_info.variant_idents();
+        let expression_idents: Vec<Vec<Ident>> = enum_info.expression_idents();
+        let variant_expressions: Vec<Vec<Expr>> = enum_info.variant_expressions();
+
+        let arm_bodies: Vec<proc_macro2::TokenStream> = variant_idents
+            .iter()
+            .map(|variant_ident| quote! { Self::#variant_ident })
+            .collect();
+        let mask_arms = mask_arms(&enum_info, &variant_idents, &arm_bodies);
+
+        let fallback = catch_all_from_number(&enum_info).unwrap_or_else(|| {
+            quote! { unsafe { ::core::hint::unreachable_unchecked() } }
+        });
+
+        quote! {
+            // Use intermediate const(s) so that enums defined like
+            // `Two = ONE + 1u8` work properly.
+            #![allow(non_upper_case_globals)]
+            #(
+                #(
+                    const #expression_idents: #repr = #variant_expressions;
+                )*
+            )*
+            #[deny(unreachable_patterns)]
+            match number {
+                #(
+                    #( #expression_idents )|* => #arm_bodies,
+                )*
+                #( #mask_arms )*
+                #[allow(unreachable_patterns)]
+                _ => #fallback,
+            }
+        }
+    } else {
+        quote! { ::core::mem::transmute(number) }
+    };
+
+    Ok(quote! {
+        #[allow(deprecated)]
         impl #krate::UnsafeFromPrimitive for #name {
             type Primitive = #repr;
 
             unsafe fn unchecked_transmute_from(number: Self::Primitive) -> Self {
-                ::core::mem::transmute(number)
+                #body
             }
         }
     })
@@ -336,22 +679,30 @@ pub fn derive_unsafe_from_primitive(stream: TokenStream) -> TokenStream {
 /// assert_eq!(Number::One, <Number as ::core::default::Default>::default());
 /// ```
 #[proc_macro_derive(Default, attributes(num_enum, default))]
-pub fn derive_default(stream: TokenStream) -> TokenStream {
-    let enum_info = parse_macro_input!(stream as EnumInfo);
+pub fn derive_default(input: TokenStream) -> TokenStream {
+    expand(input, default)
+}
+
+fn default(enum_info: EnumInfo) -> syn::Result<proc_macro2::TokenStream> {
+    enum_info.require_unit_variants()?;
 
     let default_ident = match enum_info.default() {
         Some(ident) => ident,
         None => {
             let span = Span::call_site();
-            let message =
-                "#[derive(num_enum::Default)] requires enum to be exhaustive, or a variant marked with `#[default]` or `#[num_enum(default)]`";
-            return syn::Error::new(span, message).to_compile_error().into();
+            let message = if enum_info.has_fallback() {
+                "#[derive(num_enum::Default)] requires a variant marked with `#[default]` or `#[num_enum(default)]`; `#[num_enum(fallback)]` only affects `FromPrimitive`"
+            } else {
+                "#[derive(num_enum::Default)] requires enum to be exhaustive, or a variant marked with `#[default]` or `#[num_enum(default)]`"
+            };
+            return Err(syn::Error::new(span, message));
         }
     };
 
     let EnumInfo { ref name, .. } = enum_info;
 
-    TokenStream::from(quote! {
+    Ok(quote! {
+        #[allow(deprecated)]
         impl ::core::default::Default for #name {
             #[inline]
             fn default() -> Self {
@@ -360,3 +711,1689 @@ pub fn derive_default(stream: TokenStream) -> TokenStream {
         }
     })
 }
+
+/// Implements `num_enum::EnumMetadata` for a `#[repr(Primitive)] enum`.
+///
+/// Exposes the bounds of the enum's discriminants, and whether it is exhaustive, as associated
+/// consts which can be used in generic and `const` code.
+/// ----------------------------------------------
+///
+/// ```rust
+/// use num_enum::EnumMetadata;
+///
+/// #[derive(EnumMetadata)]
+/// #[repr(u8)]
+/// enum Number {
+///     Two = 2,
+///     Three,
+///     #[num_enum(default)]
+///     Five = 5,
+/// }
+///
+/// assert_eq!(Number::MIN_DISCRIMINANT, 2);
+/// assert_eq!(Number::MAX_DISCRIMINANT, 5);
+/// assert!(!Number::IS_EXHAUSTIVE);
+/// assert!(Number::HAS_DEFAULT);
+/// assert!(!Number::HAS_FALLBACK);
+/// assert!(!Number::HAS_CATCH_ALL);
+/// assert!(!Number::IS_CONTIGUOUS);
+/// ```
+#[proc_macro_derive(EnumMetadata, attributes(num_enum, default, catch_all))]
+pub fn derive_enum_metadata(input: TokenStream) -> TokenStream {
+    expand(input, enum_metadata)
+}
+
+fn enum_metadata(enum_info: EnumInfo) -> syn::Result<proc_macro2::TokenStream> {
+    let krate = get_crate_path(enum_info.crate_path.clone());
+
+    let is_exhaustive = enum_info.is_naturally_exhaustive()?;
+    let has_default = enum_info.has_default();
+    let has_fallback = enum_info.has_fallback();
+    let has_catch_all = enum_info.has_catch_all();
+
+    let EnumInfo {
+        ref name, ref repr, ..
+    } = enum_info;
+
+    let canonical_expressions: Vec<Expr> = enum_info.canonical_expressions();
+    if canonical_expressions.is_empty() {
+        let span = Span::call_site();
+        let message = "#[derive(num_enum::EnumMetadata)] requires at least one variant which is not marked `#[num_enum(catch_all)]`";
+        return Err(syn::Error::new(span, message));
+    }
+    let discriminant_count = canonical_expressions.len() as u128;
+
+    Ok(quote! {
+        #[allow(deprecated)]
+        impl #krate::EnumMetadata for #name {
+            type Primitive = #repr;
+
+            // The discriminants are folded in generated const code, rather than at macro
+            // expansion time, so that enums defined like `Two = ONE + 1u8` work properly.
+            const MIN_DISCRIMINANT: Self::Primitive = {
+                let mut min = #repr::MAX;
+                #(
+                    if #canonical_expressions < min {
+                        min = #canonical_expressions;
+                    }
+                )*
+                min
+            };
+
+            const MAX_DISCRIMINANT: Self::Primitive = {
+                let mut max = #repr::MIN;
+                #(
+                    if #canonical_expressions > max {
+                        max = #canonical_expressions;
+                    }
+                )*
+                max
+            };
+
+            const IS_EXHAUSTIVE: bool = #is_exhaustive;
+
+            const HAS_DEFAULT: bool = #has_default;
+
+            const HAS_FALLBACK: bool = #has_fallback;
+
+            const HAS_CATCH_ALL: bool = #has_catch_all;
+
+            // Compared with one less than the count, as the span of a 128-bit enum covering its
+            // whole range would overflow.
+            const IS_CONTIGUOUS: bool = #repr::abs_diff(
+                <Self as #krate::EnumMetadata>::MAX_DISCRIMINANT,
+                <Self as #krate::EnumMetadata>::MIN_DISCRIMINANT,
+            ) as u128
+                == #discriminant_count - 1;
+        }
+    })
+}
+
+/// Implements `num_enum::Ordinal` for a `#[repr(Primitive)] enum`, along with `const` inherent
+/// methods for stepping between variants.
+///
+/// Ordinals number the variants densely from zero in declaration order, regardless of their
+/// discriminants.
+/// ----------------------------------------------
+///
+/// ```rust
+/// use num_enum::Ordinal;
+///
+/// #[derive(Debug, Eq, PartialEq, Ordinal)]
+/// #[repr(u8)]
+/// enum Flag {
+///     Low = 0x10,
+///     Mid = 0x20,
+///     High = 0x80,
+/// }
+///
+/// assert_eq!(Flag::VARIANT_COUNT, 3);
+/// assert_eq!(Flag::High.ordinal(), 2);
+/// assert_eq!(Flag::from_ordinal(1), Some(Flag::Mid));
+/// assert_eq!(Flag::from_ordinal(3), None);
+///
+/// assert_eq!(Flag::Mid.next(), Some(Flag::High));
+/// assert_eq!(Flag::High.next(), None);
+/// assert_eq!(Flag::High.wrapping_next(), Flag::Low);
+/// assert_eq!(Flag::Low.saturating_prev(), Flag::Low);
+///
+/// const SECOND: Flag = Flag::Low.saturating_next();
+/// assert_eq!(SECOND, Flag::Mid);
+/// ```
+#[proc_macro_derive(Ordinal, attributes(num_enum, default, catch_all))]
+pub fn derive_ordinal(input: TokenStream) -> TokenStream {
+    expand(input, ordinal)
+}
+
+fn ordinal(enum_info: EnumInfo) -> syn::Result<proc_macro2::TokenStream> {
+    enum_info.require_unit_variants()?;
+    let krate = get_crate_path(enum_info.crate_path.clone());
+
+    if let Some(catch_all_ident) = enum_info.catch_all() {
+        let message =
+            "#[derive(num_enum::Ordinal)] does not support variants marked `#[num_enum(catch_all)]`";
+        return Err(syn::Error::new_spanned(catch_all_ident, message));
+    }
+
+    let EnumInfo {
+        ref name, ref vis, ..
+    } = enum_info;
+
+    let variant_idents: Vec<Ident> = enum_info.variant_idents();
+    if variant_idents.is_empty() {
+        let span = Span::call_site();
+        let message = "#[derive(num_enum::Ordinal)] requires at least one variant";
+        return Err(syn::Error::new(span, message));
+    }
+    let ordinals: Vec<usize> = (0..variant_idents.len()).collect();
+    let variant_count = variant_idents.len();
+    let first_ident = &variant_idents[0];
+    let last_ident = &variant_idents[variant_count - 1];
+
+    Ok(quote! {
+        #[allow(deprecated)]
+        impl #krate::Ordinal for #name {
+            const VARIANT_COUNT: usize = #variant_count;
+
+            #[inline]
+            fn ordinal(self) -> usize {
+                #name::ordinal(self)
+            }
+
+            #[inline]
+            fn from_ordinal(ordinal: usize) -> ::core::option::Option<Self> {
+                #name::from_ordinal(ordinal)
+            }
+
+            type Array<V> = [V; #variant_count];
+
+            #[inline]
+            fn array_from_fn<V, F: ::core::ops::FnMut(usize) -> V>(f: F) -> Self::Array<V> {
+                ::core::array::from_fn(f)
+            }
+        }
+
+        #[allow(deprecated)]
+        impl #name {
+            /// Returns the zero-based position of this variant in declaration order.
+            #[inline]
+            #vis const fn ordinal(self) -> usize {
+                match self {
+                    #(
+                        Self::#variant_idents => #ordinals,
+                    )*
+                }
+            }
+
+            /// Returns the variant at the zero-based position `ordinal` in declaration order.
+            #[inline]
+            #vis const fn from_ordinal(ordinal: usize) -> ::core::option::Option<Self> {
+                match ordinal {
+                    #(
+                        #ordinals => ::core::option::Option::Some(Self::#variant_idents),
+                    )*
+                    _ => ::core::option::Option::None,
+                }
+            }
+
+            /// Returns the next variant in declaration order, or `None` for the last variant.
+            #[inline]
+            #vis const fn next(self) -> ::core::option::Option<Self> {
+                Self::from_ordinal(self.ordinal() + 1)
+            }
+
+            /// Returns the previous variant in declaration order, or `None` for the first variant.
+            #[inline]
+            #vis const fn prev(self) -> ::core::option::Option<Self> {
+                match self.ordinal() {
+                    0 => ::core::option::Option::None,
+                    ordinal => Self::from_ordinal(ordinal - 1),
+                }
+            }
+
+            /// Returns the next variant in declaration order, wrapping around to the first.
+            #[inline]
+            #vis const fn wrapping_next(self) -> Self {
+                match self.next() {
+                    ::core::option::Option::Some(next) => next,
+                    ::core::option::Option::None => Self::#first_ident,
+                }
+            }
+
+            /// Returns the previous variant in declaration order, wrapping around to the last.
+            #[inline]
+            #vis const fn wrapping_prev(self) -> Self {
+                match self.prev() {
+                    ::core::option::Option::Some(prev) => prev,
+                    ::core::option::Option::None => Self::#last_ident,
+                }
+            }
+
+            /// Returns the next variant in declaration order, staying on the last.
+            #[inline]
+            #vis const fn saturating_next(self) -> Self {
+                match self.next() {
+                    ::core::option::Option::Some(next) => next,
+                    ::core::option::Option::None => Self::#last_ident,
+                }
+            }
+
+            /// Returns the previous variant in declaration order, staying on the first.
+            #[inline]
+            #vis const fn saturating_prev(self) -> Self {
+                match self.prev() {
+                    ::core::option::Option::Some(prev) => prev,
+                    ::core::option::Option::None => Self::#first_ident,
+                }
+            }
+        }
+    })
+}
+
+/// Generates an associated const holding the discriminant of each variant, named after the variant
+/// in `SCREAMING_SNAKE_CASE` with a `_VALUE` suffix, so that raw primitives can be matched on
+/// directly.
+///
+/// With `#[num_enum(value_module = name)]`, a module called `name` is also generated, containing a
+/// plain const for each variant without the suffix. The module refers back to the enum through
+/// `super`, so this requires the enum to be declared directly in a module, rather than in a
+/// function body.
+///
+/// Variants marked `#[num_enum(catch_all)]` don't get a const.
+///
+/// ```rust
+/// use num_enum::ValueConstants;
+///
+/// const BASE: u8 = 0x10;
+///
+/// #[derive(ValueConstants)]
+/// #[num_enum(value_module = opcodes)]
+/// #[repr(u8)]
+/// enum Opcode {
+///     Add = BASE,
+///     AddImmediate,
+///     HTTPGet = 0x20,
+/// }
+///
+/// fn describe(byte: u8) -> &'static str {
+///     match byte {
+///         Opcode::ADD_VALUE => "add",
+///         Opcode::ADD_IMMEDIATE_VALUE => "add immediate",
+///         opcodes::HTTP_GET => "get",
+///         _ => "unknown",
+///     }
+/// }
+///
+/// assert_eq!(describe(0x11), "add immediate");
+/// assert_eq!(describe(0x20), "get");
+/// assert_eq!(describe(0x30), "unknown");
+/// ```
+#[proc_macro_derive(ValueConstants, attributes(num_enum, default, catch_all))]
+pub fn derive_value_constants(input: TokenStream) -> TokenStream {
+    expand(input, value_constants)
+}
+
+fn value_constants(enum_info: EnumInfo) -> syn::Result<proc_macro2::TokenStream> {
+    let krate = get_crate_path(enum_info.crate_path.clone());
+    let EnumInfo {
+        ref name,
+        ref vis,
+        ref repr,
+        ref value_module,
+        ..
+    } = enum_info;
+
+    let variant_idents: Vec<Ident> = enum_info.variant_idents();
+    let canonical_expressions: Vec<Expr> = enum_info.canonical_expressions();
+
+    let mut const_names: Vec<String> = Vec::with_capacity(variant_idents.len());
+    for ident in &variant_idents {
+        let const_name = utils::screaming_snake_case(&ident.to_string());
+        if let Some(index) = const_names.iter().position(|name| *name == const_name) {
+            let message = format!(
+                "Variants `{}` and `{}` would both have a value constant named `{}_VALUE`",
+                variant_idents[index], ident, const_name
+            );
+            return Err(syn::Error::new_spanned(ident, message));
+        }
+        const_names.push(const_name);
+    }
+
+    let value_consts: Vec<Ident> = variant_idents
+        .iter()
+        .zip(&const_names)
+        .map(|(ident, const_name)| Ident::new(&format!("{}_VALUE", const_name), ident.span()))
+        .collect();
+    let docs: Vec<String> = variant_idents
+        .iter()
+        .map(|ident| format!("The discriminant of [`{}::{}`].", name, ident))
+        .collect();
+
+    let module = value_module.as_ref().map(|module| {
+        let module_consts: Vec<Ident> = variant_idents
+            .iter()
+            .zip(&const_names)
+            .map(|(ident, const_name)| Ident::new(const_name, ident.span()))
+            .collect();
+        let module_docs: Vec<String> = variant_idents
+            .iter()
+            .map(|ident| {
+                format!(
+                    "The discriminant of [`{0}::{1}`](super::{0}::{1}).",
+                    name, ident
+                )
+            })
+            .collect();
+        let module_doc = format!(
+            "The discriminants of the variants of [`{0}`](super::{0}).",
+            name
+        );
+        quote! {
+            #[doc = #module_doc]
+            #[allow(deprecated)]
+            #vis mod #module {
+                #(
+                    #[doc = #module_docs]
+                    pub const #module_consts: #repr = super::#name::#value_consts;
+                )*
+            }
+        }
+    });
+
+    Ok(quote! {
+        #[allow(deprecated)]
+        impl #name {
+            #(
+                #[doc = #docs]
+                #vis const #value_consts: #repr = #canonical_expressions;
+            )*
+        }
+
+        #module
+
+        #[doc(hidden)]
+        #[allow(deprecated)]
+        impl #krate::HasValueConstants for #name {}
+    })
+}
+
+/// Implements `num_enum::CodeTable` for each table named in the `#[num_enum(code(..))]` attributes
+/// of the variants, for enums whose variants have different codes in different numbering schemes.
+///
+/// Each table gets a marker type, named after the enum followed by the table in `UpperCamelCase`
+/// (like `CodecRtp` for the `rtp` table of `Codec`), and the enum gets
+/// inherent `code::<Table>()` and `try_from_code::<Table>(code)` methods. Every variant other than
+/// a `catch_all` one must have a code in every table. A variant may list alternative codes after
+/// its code, like `code(mp4 = [0x61, 0x62])`. As with `FromPrimitive`, unmatched codes are
+/// converted into the `fallback`, `default` or `catch_all` variant if there is one, and variants
+/// marked `#[num_enum(skip)]` are never converted into.
+///
+/// ```rust
+/// use num_enum::CodeTables;
+///
+/// #[derive(Debug, Eq, PartialEq, CodeTables)]
+/// #[repr(u8)]
+/// enum Codec {
+///     #[num_enum(code(rtp = 0, mp4 = 0x6D))]
+///     Mulaw = 1,
+///     #[num_enum(code(rtp = 96, mp4 = [0x61, 0x62]))]
+///     Aac = 2,
+/// }
+///
+/// assert_eq!(Codec::Aac.code::<CodecRtp>(), 96);
+/// assert_eq!(Codec::Aac.code::<CodecMp4>(), 0x61);
+/// assert_eq!(Codec::try_from_code::<CodecMp4>(0x62), Ok(Codec::Aac));
+/// assert_eq!(
+///     Codec::try_from_code::<CodecRtp>(1).unwrap_err().to_string(),
+///     "No variant of enum `Codec` has the code `1` in table `rtp`",
+/// );
+/// ```
+#[proc_macro_derive(CodeTables, attributes(num_enum, default, catch_all))]
+pub fn derive_code_tables(input: TokenStream) -> TokenStream {
+    expand(input, code_tables)
+}
+
+fn code_tables(enum_info: EnumInfo) -> syn::Result<proc_macro2::TokenStream> {
+    enum_info.require_unit_variants()?;
+    let krate = get_crate_path(enum_info.crate_path.clone());
+
+    let EnumInfo {
+        ref name,
+        ref vis,
+        ref repr,
+        ..
+    } = enum_info;
+
+    let tables = enum_info.code_tables();
+    if tables.is_empty() {
+        let span = Span::call_site();
+        let message = "#[derive(num_enum::CodeTables)] requires at least one variant with a `#[num_enum(code(..))]` attribute";
+        return Err(syn::Error::new(span, message));
+    }
+
+    let decoded_variant_idents = enum_info.decoded_variant_idents();
+    let fallback = match enum_info.fallback() {
+        Some(fallback_ident) => Some(quote! { Self::#fallback_ident }),
+        None => catch_all_from_number(&enum_info),
+    };
+    let catch_all_arm = enum_info
+        .catch_all_field()
+        .map(|(catch_all_ident, member)| {
+            quote! {
+                Self::#catch_all_ident { #member: raw } => ::core::convert::From::from(
+                    ::core::clone::Clone::clone(raw)
+                ),
+            }
+        });
+
+    let mut table_impls = Vec::with_capacity(tables.len());
+    for table in tables {
+        // The marker is named after the enum too, so that enums in the same module can have tables
+        // with the same name.
+        let marker = format_ident!(
+            "{}{}",
+            name,
+            utils::upper_camel_case(&table.to_string()),
+            span = table.span()
+        );
+
+        // Every variant has a code, but only those which aren't skipped are converted into.
+        let mut variant_idents: Vec<&Ident> = vec![];
+        let mut canonical_idents: Vec<Ident> = vec![];
+        let mut canonical_expressions: Vec<&Expr> = vec![];
+        let mut decoded_idents: Vec<&Ident> = vec![];
+        let mut code_idents: Vec<Vec<Ident>> = vec![];
+        let mut code_expressions: Vec<&[Expr]> = vec![];
+        for variant in enum_info
+            .variants
+            .iter()
+            .filter(|variant| !variant.is_catch_all)
+        {
+            let Some(variant_code) = variant.codes.iter().find(|code| code.table == *table) else {
+                let message = format!(
+                    "Variant `{}::{}` has no code in table `{}`",
+                    name, variant.ident, table
+                );
+                return Err(syn::Error::new_spanned(&variant.ident, message));
+            };
+            let idents: Vec<Ident> = (0..variant_code.codes.len())
+                .map(|index| format_ident!("{}__num_enum_{}_{}__", variant.ident, table, index))
+                .collect();
+            variant_idents.push(&variant.ident);
+            canonical_idents.push(idents[0].clone());
+            canonical_expressions.push(&variant_code.codes[0]);
+            if decoded_variant_idents.contains(&variant.ident) {
+                decoded_idents.push(&variant.ident);
+                code_idents.push(idents);
+                code_expressions.push(&variant_code.codes);
+            }
+        }
+
+        let fallback = match &fallback {
+            Some(fallback) => quote! { ::core::result::Result::Ok(#fallback) },
+            None => quote! {
+                ::core::result::Result::Err(#krate::TryFromCodeError::new(number))
+            },
+        };
+        let marker_doc = format!("Marker for the `{}` code table of [`{}`].", table, name);
+        let table_name = table.to_string();
+
+        table_impls.push(quote! {
+            #[doc = #marker_doc]
+            #vis enum #marker {}
+
+            #[allow(deprecated)]
+            impl #krate::CodeTable<#marker> for #name {
+                type Primitive = #repr;
+
+                const NAME: &'static str = stringify!(#name);
+                const TABLE_NAME: &'static str = #table_name;
+
+                fn code(&self) -> Self::Primitive {
+                    // Use intermediate const(s) so that codes defined like
+                    // `code(rtp = BASE + 1)` work properly.
+                    #![allow(non_upper_case_globals)]
+                    #(
+                        const #canonical_idents: #repr = #canonical_expressions;
+                    )*
+                    match self {
+                        #(
+                            Self::#variant_idents => #canonical_idents,
+                        )*
+                        #catch_all_arm
+                    }
+                }
+
+                fn try_from_code(
+                    number: Self::Primitive,
+                ) -> ::core::result::Result<Self, #krate::TryFromCodeError<Self, #marker>> {
+                    #![allow(non_upper_case_globals)]
+                    #(
+                        #(
+                            const #code_idents: #repr = #code_expressions;
+                        )*
+                    )*
+                    #[deny(unreachable_patterns)]
+                    match number {
+                        #(
+                            #( #code_idents )|*
+                            => ::core::result::Result::Ok(Self::#decoded_idents),
+                        )*
+                        #[allow(unreachable_patterns)]
+                        _ => #fallback,
+                    }
+                }
+            }
+        });
+    }
+
+    Ok(quote! {
+        #(#table_impls)*
+
+        #[allow(deprecated)]
+        impl #name {
+            /// Returns the code of this variant in the code table `Table`.
+            #[inline]
+            #vis fn code<Table>(&self) -> <Self as #krate::CodeTable<Table>>::Primitive
+            where
+                Self: #krate::CodeTable<Table>,
+            {
+                <Self as #krate::CodeTable<Table>>::code(self)
+            }
+
+            /// Returns the variant whose code or alternative code in the code table `Table` is
+            /// `code`.
+            #[inline]
+            #vis fn try_from_code<Table>(
+                code: <Self as #krate::CodeTable<Table>>::Primitive,
+            ) -> ::core::result::Result<Self, #krate::TryFromCodeError<Self, Table>>
+            where
+                Self: #krate::CodeTable<Table>,
+            {
+                <Self as #krate::CodeTable<Table>>::try_from_code(code)
+            }
+        }
+
+        #[doc(hidden)]
+        #[allow(deprecated)]
+        impl #krate::HasCodeTables for #name {}
+    })
+}
+
+/// Generates an enum for each `#[num_enum(subset(Name = [Variant, ..]))]` attribute, holding just
+/// the listed variants, with the same discriminants.
+///
+/// Each subset enum derives `Clone`, `Copy`, `Debug`, `PartialEq`, `Eq`, `Hash`, `IntoPrimitive` and
+/// `TryFromPrimitive`, and has the same visibility and `repr` as the enum. `From<Subset>` is
+/// implemented for the enum, and `TryFrom<Enum>` for the subset, which fails with a
+/// `TryFromPrimitiveError` holding the value of variants outside the subset. The listed variants
+/// must be unit variants, and keep their alternatives, masks and `skip` attributes, but can't have
+/// attributes which only apply to the enum, like `since` or `code`.
+///
+/// ```rust
+/// use num_enum::Subsets;
+/// use std::convert::TryFrom;
+///
+/// #[derive(Debug, Eq, PartialEq, Subsets)]
+/// #[num_enum(subset(ClientError = [BadRequest, NotFound], ServerError = [Internal]))]
+/// #[repr(u16)]
+/// enum ErrorCode {
+///     BadRequest = 400,
+///     NotFound = 404,
+///     Internal = 500,
+///     Unavailable = 503,
+/// }
+///
+/// fn reject(error: ClientError) -> ErrorCode {
+///     error.into()
+/// }
+///
+/// assert_eq!(reject(ClientError::NotFound), ErrorCode::NotFound);
+/// assert_eq!(u16::from(ServerError::Internal), 500);
+/// assert_eq!(ServerError::try_from(ErrorCode::Internal), Ok(ServerError::Internal));
+/// assert_eq!(ServerError::try_from(ErrorCode::BadRequest).unwrap_err().number, 400);
+/// ```
+#[proc_macro_derive(Subsets, attributes(num_enum, default, catch_all))]
+pub fn derive_subsets(input: TokenStream) -> TokenStream {
+    expand(input, subsets)
+}
+
+fn subsets(enum_info: EnumInfo) -> syn::Result<proc_macro2::TokenStream> {
+    let krate = get_crate_path(enum_info.crate_path.clone());
+    let name = &enum_info.name;
+
+    // The marker is implemented even if the subsets are invalid, so that the other derives don't
+    // also report the `subset` attribute as needing `Subsets`.
+    let subset_enums =
+        subset_enums(&enum_info, &krate).unwrap_or_else(syn::Error::into_compile_error);
+
+    Ok(quote! {
+        #subset_enums
+
+        #[doc(hidden)]
+        #[allow(deprecated)]
+        impl #krate::HasSubsets for #name {}
+    })
+}
+
+fn subset_enums(enum_info: &EnumInfo, krate: &syn::Path) -> syn::Result<proc_macro2::TokenStream> {
+    let EnumInfo {
+        ref name,
+        ref vis,
+        ref repr,
+        ref subsets,
+        ..
+    } = enum_info;
+
+    if subsets.is_empty() {
+        let span = Span::call_site();
+        let message = "#[derive(num_enum::Subsets)] requires at least one `#[num_enum(subset(..))]` attribute";
+        return Err(syn::Error::new(span, message));
+    }
+
+    // The repr is only a path rather than an integer type for `repr(C)`.
+    let subset_repr = match repr.get_ident() {
+        Some(ident) => quote! { #ident },
+        None => quote! { C },
+    };
+    let superset_value = primitive_value_of_ref(enum_info, quote! { &enum_value });
+
+    let mut subset_enums = Vec::with_capacity(subsets.len());
+    for subset in subsets {
+        let subset_name = &subset.name;
+        if subset.variants.is_empty() {
+            let message = format!("Subset `{}` must contain at least one variant", subset_name);
+            return Err(syn::Error::new_spanned(subset_name, message));
+        }
+
+        let mut variants = Vec::with_capacity(subset.variants.len());
+        for (index, ident) in subset.variants.iter().enumerate() {
+            if subset.variants[..index].contains(ident) {
+                let message = format!(
+                    "`{}` is listed more than once in subset `{}`",
+                    ident, subset_name
+                );
+                return Err(syn::Error::new_spanned(ident, message));
+            }
+            let Some(variant) = enum_info.variants.iter().find(|v| v.ident == *ident) else {
+                let message = format!("`{}` isn't a variant of `{}`", ident, name);
+                return Err(syn::Error::new_spanned(ident, message));
+            };
+            if variant.fields != syn::Fields::Unit {
+                let message = format!(
+                    "Subset `{}` can't contain `{}`, as it isn't a unit variant",
+                    subset_name, ident
+                );
+                return Err(syn::Error::new_spanned(ident, message));
+            }
+            variants.push(variant);
+        }
+
+        let subset_doc = format!("A subset of the variants of [`{}`].", name);
+        let variant_idents: Vec<&Ident> = variants.iter().map(|variant| &variant.ident).collect();
+        let canonical_values = variants.iter().map(|variant| &variant.canonical_value);
+        let value_attrs = variants
+            .iter()
+            .map(|variant| variant.value_attrs("Subsets"))
+            .collect::<syn::Result<Vec<_>>>()?;
+
+        subset_enums.push(quote! {
+            #[doc = #subset_doc]
+            #[derive(
+                ::core::clone::Clone,
+                ::core::marker::Copy,
+                ::core::fmt::Debug,
+                ::core::cmp::PartialEq,
+                ::core::cmp::Eq,
+                ::core::hash::Hash,
+                #krate::IntoPrimitive,
+                #krate::TryFromPrimitive,
+            )]
+            #[num_enum(crate = #krate)]
+            #[repr(#subset_repr)]
+            #vis enum #subset_name {
+                #(
+                    #value_attrs
+                    #variant_idents = #canonical_values,
+                )*
+            }
+
+            #[allow(deprecated)]
+            impl From<#subset_name> for #name {
+                #[inline]
+                fn from(enum_value: #subset_name) -> Self {
+                    match enum_value {
+                        #(
+                            #subset_name::#variant_idents => Self::#variant_idents,
+                        )*
+                    }
+                }
+            }
+
+            #[allow(deprecated)]
+            impl ::core::convert::TryFrom<#name> for #subset_name {
+                type Error = #krate::TryFromPrimitiveError<Self>;
+
+                #[inline]
+                fn try_from(enum_value: #name) -> ::core::result::Result<Self, Self::Error> {
+                    #[deny(unreachable_patterns)]
+                    match enum_value {
+                        #(
+                            #name::#variant_idents => ::core::result::Result::Ok(Self::#variant_idents),
+                        )*
+                        #[allow(unreachable_patterns)]
+                        enum_value => ::core::result::Result::Err(
+                            #krate::TryFromPrimitiveError::new(#superset_value),
+                        ),
+                    }
+                }
+            }
+        });
+    }
+
+    Ok(quote! {
+        #(#subset_enums)*
+    })
+}
+
+/// Implements `TryFrom<(Primitive, &[u8])>` for a `#[repr(Primitive)] enum` whose variants have
+/// fields, choosing the variant by its discriminant (its tag), and decoding each of its fields in
+/// turn from the payload with `num_enum::DecodePayload`.
+///
+/// Also generates a field-less `{Enum}Tag` enum with the same variants and discriminants, which
+/// derives `TryFromPrimitive` and `IntoPrimitive`, along with inherent `tag` and `encode_tagged`
+/// methods, which encode the fields with `num_enum::EncodePayload` for round-tripping.
+///
+/// ```rust
+/// use num_enum::{PayloadError, TaggedPayload, TaggedPayloadError};
+/// use std::convert::TryFrom;
+///
+/// #[derive(Debug, Eq, PartialEq, TaggedPayload)]
+/// #[repr(u8)]
+/// enum Message {
+///     Ping { id: u32 } = 1,
+///     Move(i16, i16) = 5,
+///     Close,
+/// }
+///
+/// assert_eq!(
+///     Message::try_from((1u8, &[7, 0, 0, 0][..])),
+///     Ok(Message::Ping { id: 7 }),
+/// );
+/// assert_eq!(MessageTag::try_from(6u8), Ok(MessageTag::Close));
+///
+/// let mut buf = [0u8; 8];
+/// let (tag, len) = Message::Move(-1, 2).encode_tagged(&mut buf).unwrap();
+/// assert_eq!((tag, &buf[..len]), (5u8, &[0xff, 0xff, 2, 0][..]));
+///
+/// assert!(matches!(
+///     Message::try_from((2u8, &[][..])),
+///     Err(TaggedPayloadError::UnknownTag(_)),
+/// ));
+/// assert!(matches!(
+///     Message::try_from((1u8, &[7][..])),
+///     Err(TaggedPayloadError::Payload(PayloadError::UnexpectedEnd)),
+/// ));
+/// ```
+#[proc_macro_derive(TaggedPayload, attributes(num_enum))]
+pub fn derive_tagged_payload(input: TokenStream) -> TokenStream {
+    expand(input, tagged_payload)
+}
+
+fn tagged_payload(enum_info: EnumInfo) -> syn::Result<proc_macro2::TokenStream> {
+    let krate = get_crate_path(enum_info.crate_path.clone());
+
+    if let Some(catch_all_ident) = enum_info.catch_all() {
+        let message = "#[derive(num_enum::TaggedPayload)] does not support variants marked `#[num_enum(catch_all)]`";
+        return Err(syn::Error::new_spanned(catch_all_ident, message));
+    }
+
+    let EnumInfo {
+        ref name,
+        ref vis,
+        ref repr,
+        ref variants,
+        ..
+    } = enum_info;
+
+    let tag_name = format_ident!("{}Tag", name);
+    let tag_doc = format!("The tag of each variant of [`{}`].", name);
+    // The repr is only a path rather than an integer type for `repr(C)`.
+    let tag_repr = match repr.get_ident() {
+        Some(ident) => quote! { #ident },
+        None => quote! { C },
+    };
+
+    let variant_idents: Vec<&Ident> = variants.iter().map(|variant| &variant.ident).collect();
+    let canonical_values: Vec<&Expr> = variants
+        .iter()
+        .map(|variant| &variant.canonical_value)
+        .collect();
+    let value_attrs = variants
+        .iter()
+        .map(|variant| variant.value_attrs("TaggedPayload"))
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    let mut decode_arms = Vec::with_capacity(variants.len());
+    let mut encode_arms = Vec::with_capacity(variants.len());
+    for variant in variants {
+        let ident = &variant.ident;
+        let members: Vec<syn::Member> = variant
+            .fields
+            .iter()
+            .enumerate()
+            .map(|(index, field)| match &field.ident {
+                Some(field_ident) => syn::Member::Named(field_ident.clone()),
+                None => syn::Member::Unnamed(index.into()),
+            })
+            .collect();
+        let bindings: Vec<Ident> = (0..members.len())
+            .map(|index| format_ident!("field_{}", index))
+            .collect();
+        let types: Vec<&syn::Type> = variant.fields.iter().map(|field| &field.ty).collect();
+
+        decode_arms.push(quote! {
+            #tag_name::#ident => {
+                #(
+                    let (#bindings, rest) = <#types as #krate::DecodePayload>::decode_payload(rest)
+                        .map_err(#krate::TaggedPayloadError::Payload)?;
+                )*
+                (Self::#ident { #(#members: #bindings),* }, rest)
+            }
+        });
+        encode_arms.push(if bindings.is_empty() {
+            quote! { Self::#ident { .. } => 0, }
+        } else {
+            quote! {
+                Self::#ident { #(#members: #bindings),* } => {
+                    let mut len = 0;
+                    #(
+                        len += #krate::EncodePayload::encode_payload(#bindings, &mut buf[len..])?;
+                    )*
+                    len
+                }
+            }
+        });
+    }
+
+    Ok(quote! {
+        #[doc = #tag_doc]
+        #[derive(
+            ::core::clone::Clone,
+            ::core::marker::Copy,
+            ::core::fmt::Debug,
+            ::core::cmp::PartialEq,
+            ::core::cmp::Eq,
+            ::core::hash::Hash,
+            #krate::IntoPrimitive,
+            #krate::TryFromPrimitive,
+        )]
+        #[num_enum(crate = #krate)]
+        #[repr(#tag_repr)]
+        #vis enum #tag_name {
+            #(
+                #value_attrs
+                #variant_idents = #canonical_values,
+            )*
+        }
+
+        #[allow(deprecated)]
+        impl ::core::convert::TryFrom<(#repr, &[u8])> for #name {
+            type Error = #krate::TaggedPayloadError<#tag_name>;
+
+            fn try_from(
+                (tag, payload): (#repr, &[u8]),
+            ) -> ::core::result::Result<Self, Self::Error> {
+                let tag = <#tag_name as #krate::TryFromPrimitive>::try_from_primitive(tag)
+                    .map_err(#krate::TaggedPayloadError::UnknownTag)?;
+                let rest = payload;
+                let (value, rest) = match tag {
+                    #(#decode_arms)*
+                };
+                if !rest.is_empty() {
+                    return ::core::result::Result::Err(#krate::TaggedPayloadError::Payload(
+                        #krate::PayloadError::TrailingBytes,
+                    ));
+                }
+                ::core::result::Result::Ok(value)
+            }
+        }
+
+        #[allow(deprecated)]
+        impl #name {
+            /// Returns the tag of this variant.
+            #[inline]
+            #vis fn tag(&self) -> #tag_name {
+                match self {
+                    #(
+                        Self::#variant_idents { .. } => #tag_name::#variant_idents,
+                    )*
+                }
+            }
+
+            /// Encodes the fields of this variant into the start of `buf`, returning the variant's
+            /// tag, and the number of bytes written.
+            #vis fn encode_tagged(
+                &self,
+                buf: &mut [u8],
+            ) -> ::core::result::Result<(#repr, usize), #krate::PayloadError> {
+                let len = match self {
+                    #(#encode_arms)*
+                };
+                ::core::result::Result::Ok((::core::convert::From::from(self.tag()), len))
+            }
+        }
+    })
+}
+
+/// Parses the input of a derive, and generates its output with `derive`, or the compile error
+/// describing why it couldn't.
+fn expand(
+    input: TokenStream,
+    derive: fn(EnumInfo) -> syn::Result<proc_macro2::TokenStream>,
+) -> TokenStream {
+    syn::parse::<EnumInfo>(input)
+        .and_then(|enum_info| {
+            let required_derive_assertions = required_derive_assertions(&enum_info);
+            let output = derive(enum_info)?;
+            Ok(quote! {
+                #output
+
+                #required_derive_assertions
+            })
+        })
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Asserts that the enum also derives each macro which reads one of its attributes, so that an
+/// attribute which would be ignored is a compile error, pointing at the attribute.
+fn required_derive_assertions(enum_info: &EnumInfo) -> proc_macro2::TokenStream {
+    let krate = get_crate_path(enum_info.crate_path.clone());
+    let name = &enum_info.name;
+    let repr = &enum_info.repr;
+
+    let assertions = enum_info.required_derives.iter().map(|required| {
+        let span = required.span;
+        let bound = match required.derive {
+            Derive::IntoPrimitive => quote_spanned! {span=> T: ::core::convert::Into<#repr> },
+            Derive::FromPrimitive => quote_spanned! {span=> T: #krate::FromPrimitive },
+            Derive::TryFromPrimitive => quote_spanned! {span=> T: #krate::TryFromPrimitive },
+            Derive::ValueConstants => quote_spanned! {span=> T: #krate::HasValueConstants },
+            Derive::CodeTables => quote_spanned! {span=> T: #krate::HasCodeTables },
+            Derive::Subsets => quote_spanned! {span=> T: #krate::HasSubsets },
+        };
+        // Point the error at the attribute rather than the enum.
+        let name = Ident::new(&name.to_string(), span);
+        // The error notes that the bound is required by this function, so name it after the cause.
+        let function = format_ident!(
+            "{}_requires_derive_{}",
+            required.attribute,
+            required.derive.snake_case_name(),
+            span = span
+        );
+        quote_spanned! {span=>
+            #[allow(deprecated)]
+            const _: () = {
+                fn #function<T>()
+                where
+                    #bound,
+                {
+                }
+                let _ = #function::<#name>;
+            };
+        }
+    });
+    quote! {
+        #(#assertions)*
+    }
+}
+
+/// Generates the statements to run when `number` is converted to each variant, in the same order
+/// as `EnumInfo::decoded_variant_idents`.
+///
+/// These are empty unless the variant is `#[deprecated]` and the enum has
+/// `#[num_enum(deprecated_value_warning)]`.
+fn deprecated_value_warnings(
+    enum_info: &EnumInfo,
+    krate: &syn::Path,
+) -> Vec<proc_macro2::TokenStream> {
+    let name = &enum_info.name;
+    enum_info
+        .decoded_variants()
+        .map(|variant| match &enum_info.deprecated_value_warning {
+            Some(warning) if variant.is_deprecated => {
+                let variant_name = format!("{}::{}", name, variant.ident);
+                match &warning.hook {
+                    Some(hook) => quote! { #hook(#variant_name, number); },
+                    None => quote! {
+                        #krate::log::warn!(
+                            "Converted {:?} into deprecated variant `{}`",
+                            number,
+                            #variant_name,
+                        );
+                    },
+                }
+            }
+            _ => quote! {},
+        })
+        .collect()
+}
+
+/// Generates a guarded match arm for each variant with `#[num_enum(mask = .., value = ..)]`, which
+/// evaluates the element of `arm_bodies` at the same index as the variant in `variant_idents`.
+///
+/// These must come after the arms for the variants' exact values, which may also be masked.
+fn mask_arms(
+    enum_info: &EnumInfo,
+    variant_idents: &[Ident],
+    arm_bodies: &[proc_macro2::TokenStream],
+) -> Vec<proc_macro2::TokenStream> {
+    enum_info
+        .masks()
+        .into_iter()
+        .filter_map(|(variant_ident, mask, value)| {
+            let index = variant_idents
+                .iter()
+                .position(|ident| ident == variant_ident)?;
+            let arm_body = &arm_bodies[index];
+            Some(quote! {
+                number if number & #mask == #value => #arm_body,
+            })
+        })
+        .collect()
+}
+
+/// Generates `is_valid_in` and `try_from_primitive_versioned` methods, for enums with variants
+/// marked `#[num_enum(since = ..)]` or `#[num_enum(until = ..)]`.
+fn versioned_impl(enum_info: &EnumInfo, krate: &syn::Path) -> proc_macro2::TokenStream {
+    let EnumInfo {
+        ref name, ref vis, ..
+    } = enum_info;
+
+    let versioned_arms = enum_info
+        .variants
+        .iter()
+        .filter(|variant| variant.since.is_some() || variant.until.is_some())
+        .map(|variant| {
+            let ident = &variant.ident;
+            let since = variant.since.map(|since| quote! { version >= #since });
+            let until = variant.until.map(|until| quote! { version <= #until });
+            let conditions = since.into_iter().chain(until);
+            quote! {
+                Self::#ident { .. } => #( #conditions )&&*,
+            }
+        });
+
+    quote! {
+        #[allow(deprecated)]
+        impl #name {
+            /// Returns whether this variant is valid in protocol version `version`, according to
+            /// its `#[num_enum(since = ..)]` and `#[num_enum(until = ..)]` attributes, which are
+            /// both inclusive.
+            #[inline]
+            #vis const fn is_valid_in(&self, version: u32) -> bool {
+                match self {
+                    #(#versioned_arms)*
+                    #[allow(unreachable_patterns)]
+                    _ => true,
+                }
+            }
+
+            /// Like `try_from_primitive`, but rejects variants which aren't valid in protocol
+            /// version `version` with a distinct error.
+            #vis fn try_from_primitive_versioned(
+                number: <Self as #krate::TryFromPrimitive>::Primitive,
+                version: u32,
+            ) -> ::core::result::Result<Self, #krate::TryFromPrimitiveVersionedError<Self>> {
+                match <Self as #krate::TryFromPrimitive>::try_from_primitive(number) {
+                    ::core::result::Result::Ok(variant) if variant.is_valid_in(version) => {
+                        ::core::result::Result::Ok(variant)
+                    }
+                    ::core::result::Result::Ok(_) => ::core::result::Result::Err(
+                        #krate::TryFromPrimitiveVersionedError::NotInVersion { number, version },
+                    ),
+                    ::core::result::Result::Err(err) => ::core::result::Result::Err(
+                        #krate::TryFromPrimitiveVersionedError::Unknown(err),
+                    ),
+                }
+            }
+        }
+    }
+}
+
+/// Generates `#[no_mangle] extern "C"` functions named `{prefix}_is_valid`, `{prefix}_name` and
+/// `{prefix}_try_from`, which let C code check and convert primitives using `TryFromPrimitive`.
+///
+/// They're generated in an anonymous const, so are only reachable through their symbols.
+fn ffi_export_fns(
+    enum_info: &EnumInfo,
+    krate: &syn::Path,
+    prefix: &str,
+) -> proc_macro2::TokenStream {
+    let EnumInfo {
+        ref name, ref repr, ..
+    } = enum_info;
+
+    let is_valid_fn = format_ident!("{}_is_valid", prefix);
+    let name_fn = format_ident!("{}_name", prefix);
+    let try_from_fn = format_ident!("{}_try_from", prefix);
+
+    let variant_idents: Vec<&Ident> = enum_info
+        .variants
+        .iter()
+        .map(|variant| &variant.ident)
+        .collect();
+    let variant_names = variant_idents
+        .iter()
+        .map(|ident| syn::LitStr::new(&format!("{}\0", ident), ident.span()));
+    let value = primitive_value_of_ref(enum_info, quote! { &enum_value });
+
+    quote! {
+        #[allow(deprecated)]
+        const _: () = {
+            #[no_mangle]
+            pub extern "C" fn #is_valid_fn(value: #repr) -> bool {
+                <#name as #krate::TryFromPrimitive>::try_from_primitive(value).is_ok()
+            }
+
+            // Returns a nul-terminated static string, or null if the value is invalid.
+            #[no_mangle]
+            pub extern "C" fn #name_fn(value: #repr) -> *const ::core::ffi::c_char {
+                match <#name as #krate::TryFromPrimitive>::try_from_primitive(value) {
+                    ::core::result::Result::Ok(enum_value) => {
+                        let name: &'static str = match enum_value {
+                            #(
+                                #name::#variant_idents { .. } => #variant_names,
+                            )*
+                        };
+                        name.as_ptr().cast()
+                    }
+                    ::core::result::Result::Err(_) => ::core::ptr::null(),
+                }
+            }
+
+            // Writes the discriminant of the variant the value converts into to `out`, unless it's
+            // null.
+            //
+            // `Option<&mut _>` has the same ABI as a nullable pointer, so C passes null for `None`.
+            #[no_mangle]
+            pub extern "C" fn #try_from_fn(
+                value: #repr,
+                out: ::core::option::Option<&mut #repr>,
+            ) -> bool {
+                match <#name as #krate::TryFromPrimitive>::try_from_primitive(value) {
+                    ::core::result::Result::Ok(enum_value) => {
+                        if let ::core::option::Option::Some(out) = out {
+                            *out = #value;
+                        }
+                        true
+                    }
+                    ::core::result::Result::Err(_) => false,
+                }
+            }
+        };
+    }
+}
+
+/// Generates an expression constructing the catch-all variant, if there is one, from `number`.
+/// Asserts that none of the discriminants of a `#[num_enum(nonzero)]` enum evaluate to 0.
+fn nonzero_assertions(enum_info: &EnumInfo) -> proc_macro2::TokenStream {
+    // Literal discriminants of zero are rejected while parsing, but other expressions can only be
+    // checked once they're evaluated.
+    let assertions = enum_info
+        .canonical_expressions()
+        .into_iter()
+        .filter(|expr| match expr {
+            Expr::Lit(_) => false,
+            Expr::Unary(syn::ExprUnary {
+                op: syn::UnOp::Neg(_),
+                expr,
+                ..
+            }) => !matches!(**expr, Expr::Lit(_)),
+            _ => true,
+        })
+        .map(|expr| {
+            quote_spanned! {expr.span()=>
+                const _: () = ::core::assert!(
+                    #expr != 0,
+                    "`#[num_enum(nonzero)]` enums can't have a variant with the discriminant 0",
+                );
+            }
+        });
+    quote! {
+        #(#assertions)*
+    }
+}
+
+fn catch_all_from_number(enum_info: &EnumInfo) -> Option<proc_macro2::TokenStream> {
+    enum_info
+        .catch_all_field()
+        .map(|(catch_all_ident, member)| {
+            quote! { Self::#catch_all_ident { #member: ::core::convert::From::from(number) } }
+        })
+}
+
+/// Generates an expression evaluating to the primitive value of `enum_ref`, which must be a
+/// reference to the enum.
+///
+/// Unlike an `as` cast, this works for enums with a catch-all variant, and doesn't require the
+/// enum to be `Copy`.
+fn primitive_value_of_ref(
+    enum_info: &EnumInfo,
+    enum_ref: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let EnumInfo {
+        ref name, ref repr, ..
+    } = enum_info;
+
+    let variant_idents: Vec<Ident> = enum_info.variant_idents();
+    let canonical_idents: Vec<Ident> = enum_info
+        .expression_idents()
+        .into_iter()
+        .map(|idents| idents[0].clone())
+        .collect();
+    let canonical_expressions: Vec<Expr> = enum_info.canonical_expressions();
+    let catch_all_arm = enum_info
+        .catch_all_field()
+        .map(|(catch_all_ident, member)| {
+            quote! {
+                #name::#catch_all_ident { #member: raw } => ::core::convert::From::from(
+                    ::core::clone::Clone::clone(raw)
+                ),
+            }
+        });
+
+    quote! {
+        {
+            // Use intermediate const(s) so that enums defined like
+            // `Two = ONE + 1u8` work properly.
+            #(
+                #[allow(non_upper_case_globals)]
+                const #canonical_idents: #repr = #canonical_expressions;
+            )*
+            match #enum_ref {
+                #(
+                    #name::#variant_idents { .. } => #canonical_idents,
+                )*
+                #catch_all_arm
+            }
+        }
+    }
+}
+
+/// Implements `PartialEq`, `Eq`, `Hash`, `PartialOrd` and `Ord` in terms of the enum's primitive
+/// value, so that a catch-all variant holding the discriminant of another variant is equal to it.
+fn value_eq_impls(enum_info: &EnumInfo) -> proc_macro2::TokenStream {
+    let EnumInfo {
+        ref name, ref repr, ..
+    } = enum_info;
+    let value = primitive_value_of_ref(enum_info, quote! { enum_value });
+    let value_fn = quote! {
+        #[inline]
+        fn primitive_value(enum_value: &#name) -> #repr {
+            #value
+        }
+    };
+
+    quote! {
+        #[allow(deprecated)]
+        impl ::core::cmp::PartialEq for #name {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                #value_fn
+                primitive_value(self) == primitive_value(other)
+            }
+        }
+
+        #[allow(deprecated)]
+        impl ::core::cmp::Eq for #name {}
+
+        #[allow(deprecated)]
+        impl ::core::hash::Hash for #name {
+            #[inline]
+            fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                #value_fn
+                ::core::hash::Hash::hash(&primitive_value(self), state)
+            }
+        }
+
+        #[allow(deprecated)]
+        impl ::core::cmp::PartialOrd for #name {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> ::core::option::Option<::core::cmp::Ordering> {
+                ::core::option::Option::Some(::core::cmp::Ord::cmp(self, other))
+            }
+        }
+
+        #[allow(deprecated)]
+        impl ::core::cmp::Ord for #name {
+            #[inline]
+            fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+                #value_fn
+                ::core::cmp::Ord::cmp(&primitive_value(self), &primitive_value(other))
+            }
+        }
+    }
+}
+
+/// Implements conversions into the enums named by `#[num_enum(convert(..))]` attributes.
+///
+/// Converting by name always implements `From`, leaving rustc to report variants without a
+/// counterpart. Converting by value implements `From` using the target's `TryFromPrimitive`, with
+/// const assertions that the target has a variant with the value of each variant without a
+/// `maps_to` override. It implements `TryFrom` instead if the attribute is `fallible`, or if a
+/// `catch_all` variant has no override, as it could hold any value.
+fn convert_impls(enum_info: &EnumInfo, krate: &syn::Path) -> syn::Result<proc_macro2::TokenStream> {
+    let EnumInfo {
+        ref name, ref repr, ..
+    } = enum_info;
+
+    let mut impls = vec![];
+    for convert in &enum_info.convert {
+        let to = &convert.to;
+        let mut override_targets = vec![];
+        let mut unmapped = vec![];
+        for variant in &enum_info.variants {
+            let maps_to = variant
+                .maps_to
+                .iter()
+                .find(|path| enum_of_variant_path(path).as_ref() == Some(to));
+            match maps_to {
+                Some(path) => override_targets.push((&variant.ident, path)),
+                None => unmapped.push(variant),
+            }
+        }
+        let override_idents = override_targets.iter().map(|(ident, _)| ident);
+        let override_paths = override_targets.iter().map(|(_, path)| path);
+
+        let impl_tokens = match convert.by {
+            ConvertBy::Name => {
+                let mut unmapped_idents = vec![];
+                for variant in unmapped {
+                    if variant.is_catch_all {
+                        return Err(syn::Error::new_spanned(
+                            &variant.ident,
+                            format!(
+                                "The `catch_all` variant can't be converted into `{}` by name, so needs a `#[num_enum(maps_to = ..)]` attribute",
+                                to.to_token_stream()
+                            ),
+                        ));
+                    }
+                    unmapped_idents.push(&variant.ident);
+                }
+                // The target variants keep the spans of the idents, so that rustc reports a
+                // missing counterpart at the variant without one.
+                quote! {
+                    #[allow(deprecated)]
+                    impl From<#name> for #to {
+                        #[inline]
+                        fn from(enum_value: #name) -> Self {
+                            match enum_value {
+                                #(
+                                    #name::#override_idents { .. } => #override_paths,
+                                )*
+                                #(
+                                    #name::#unmapped_idents { .. } => #to::#unmapped_idents,
+                                )*
+                            }
+                        }
+                    }
+                }
+            }
+            ConvertBy::Value
+                if !convert.fallible && unmapped.iter().all(|variant| !variant.is_catch_all) =>
+            {
+                // Each value is checked when the target's discriminants are evaluated, with the
+                // error pointing at the variant without a counterpart.
+                let assertions = unmapped.iter().map(|variant| {
+                    let value = &variant.canonical_value;
+                    let message = format!(
+                        "`{}::{}` has no counterpart with the same value in `{}`; mark the conversion `fallible` to implement `TryFrom` instead",
+                        name,
+                        variant.ident,
+                        to.to_token_stream().to_string().replace(' ', ""),
+                    );
+                    quote_spanned! {variant.ident.span()=>
+                        #[allow(deprecated)]
+                        const _: () = ::core::assert!(
+                            #to::__num_enum_is_valid_primitive(#value),
+                            #message,
+                        );
+                    }
+                });
+                let value_arm = (!unmapped.is_empty()).then(|| {
+                    quote! {
+                        enum_value => match <#to as #krate::TryFromPrimitive>::try_from_primitive(
+                            <#repr as ::core::convert::From<#name>>::from(enum_value),
+                        ) {
+                            ::core::result::Result::Ok(target_value) => target_value,
+                            ::core::result::Result::Err(_) => {
+                                unreachable!("every value is checked at compile time")
+                            }
+                        },
+                    }
+                });
+                quote! {
+                    #(#assertions)*
+
+                    #[allow(deprecated)]
+                    impl From<#name> for #to {
+                        #[inline]
+                        fn from(enum_value: #name) -> Self {
+                            match enum_value {
+                                #(
+                                    #name::#override_idents { .. } => #override_paths,
+                                )*
+                                #value_arm
+                            }
+                        }
+                    }
+                }
+            }
+            ConvertBy::Value => quote! {
+                #[allow(deprecated)]
+                impl ::core::convert::TryFrom<#name> for #to {
+                    type Error = <#to as #krate::TryFromPrimitive>::Error;
+
+                    #[inline]
+                    fn try_from(enum_value: #name) -> ::core::result::Result<Self, Self::Error> {
+                        match enum_value {
+                            #(
+                                #name::#override_idents { .. } => ::core::result::Result::Ok(#override_paths),
+                            )*
+                            enum_value => <#to as #krate::TryFromPrimitive>::try_from_primitive(
+                                <#repr as ::core::convert::From<#name>>::from(enum_value),
+                            ),
+                        }
+                    }
+                }
+            },
+        };
+        impls.push(impl_tokens);
+    }
+
+    Ok(quote! {
+        #(#impls)*
+    })
+}
+
+const NUM_TRAITS_INTEGER_TYPES: [&str; 12] = [
+    "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32", "u64", "u128", "usize",
+];
+
+/// Implements `num_traits::FromPrimitive`, by narrowing each integer type to the repr, and then
+/// evaluating `convert` (an `Option<Self>`) with the narrowed value bound to `number`.
+fn num_traits_from_primitive(
+    enum_info: &EnumInfo,
+    krate: &syn::Path,
+    convert: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let EnumInfo {
+        ref name, ref repr, ..
+    } = enum_info;
+
+    let integer_types: Vec<Ident> = NUM_TRAITS_INTEGER_TYPES
+        .iter()
+        .map(|ty| Ident::new(ty, Span::call_site()))
+        .collect();
+    let method_names: Vec<Ident> = NUM_TRAITS_INTEGER_TYPES
+        .iter()
+        .map(|ty| format_ident!("from_{}", ty))
+        .collect();
+
+    quote! {
+        #[allow(deprecated)]
+        impl #krate::num_traits::FromPrimitive for #name {
+            #(
+                #[inline]
+                fn #method_names(n: #integer_types) -> ::core::option::Option<Self> {
+                    match <#repr as ::core::convert::TryFrom<#integer_types>>::try_from(n) {
+                        ::core::result::Result::Ok(number) => #convert,
+                        ::core::result::Result::Err(_) => ::core::option::Option::None,
+                    }
+                }
+            )*
+        }
+    }
+}
+
+/// Implements `num_traits::ToPrimitive`, by widening or narrowing the enum's primitive value.
+fn num_traits_to_primitive(enum_info: &EnumInfo, krate: &syn::Path) -> proc_macro2::TokenStream {
+    let name = &enum_info.name;
+    let value = primitive_value_of_ref(enum_info, quote! { self });
+
+    // The remaining methods are provided in terms of these, and each of them repeats the match,
+    // so avoid generating more than needed.
+    let widest_types = ["i64", "u64", "i128", "u128"];
+    let integer_types: Vec<Ident> = widest_types
+        .iter()
+        .map(|ty| Ident::new(ty, Span::call_site()))
+        .collect();
+    let method_names: Vec<Ident> = widest_types
+        .iter()
+        .map(|ty| format_ident!("to_{}", ty))
+        .collect();
+
+    quote! {
+        #[allow(deprecated)]
+        impl #krate::num_traits::ToPrimitive for #name {
+            #(
+                #[inline]
+                fn #method_names(&self) -> ::core::option::Option<#integer_types> {
+                    #krate::num_traits::ToPrimitive::#method_names(&#value)
+                }
+            )*
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::parsing::EnumInfo;
+    use proc_macro2::{TokenStream, TokenTree};
+
+    type Derive = fn(EnumInfo) -> syn::Result<TokenStream>;
+
+    /// Every derive other than `UnsafeFromPrimitive`, which is the only one allowed to generate
+    /// `unsafe` code.
+    const SAFE_DERIVES: &[(&str, Derive)] = &[
+        ("IntoPrimitive", super::into_primitive),
+        ("FromPrimitive", super::from_primitive),
+        ("TryFromPrimitive", super::try_from_primitive),
+        ("Default", super::default),
+        ("EnumMetadata", super::enum_metadata),
+        ("Ordinal", super::ordinal),
+        ("ValueConstants", super::value_constants),
+        ("CodeTables", super::code_tables),
+        ("Subsets", super::subsets),
+        ("TaggedPayload", super::tagged_payload),
+    ];
+
+    fn contains_unsafe(tokens: TokenStream) -> bool {
+        tokens.into_iter().any(|token| match token {
+            TokenTree::Ident(ident) => ident == "unsafe",
+            TokenTree::Group(group) => contains_unsafe(group.stream()),
+            _ => false,
+        })
+    }
+
+    fn assert_safe(input: &str, derives: &[&str]) {
+        for derive in derives {
+            let (_, expand) = SAFE_DERIVES
+                .iter()
+                .find(|(name, _)| name == derive)
+                .unwrap();
+            let enum_info: EnumInfo = syn::parse_str(input).unwrap();
+            let output = expand(enum_info)
+                .unwrap_or_else(|err| panic!("{} failed for {}: {}", derive, input, err));
+            assert!(
+                !contains_unsafe(output),
+                "{} generated unsafe code for {}",
+                derive,
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn safe_derives_generate_no_unsafe_code() {
+        assert_safe(
+            "#[num_enum(crate = ::num_enum, num_traits, value_module = numbers)]
+            #[num_enum(subset(Small = [Zero, Other]))]
+            #[repr(u8)]
+            enum Number {
+                Zero,
+                #[num_enum(alternatives = [3], since = 2)]
+                One,
+                #[num_enum(code(table = 20), mask = 0xf0, value = 0x40)]
+                Two = 2,
+                #[num_enum(default)]
+                Other = 9,
+            }",
+            &[
+                "IntoPrimitive",
+                "FromPrimitive",
+                "Default",
+                "EnumMetadata",
+                "Ordinal",
+                "ValueConstants",
+                "Subsets",
+            ],
+        );
+        assert_safe(
+            "#[num_enum(crate = ::num_enum, nonzero)]
+            #[repr(u16)]
+            enum NonZero {
+                #[num_enum(code(table = 1))]
+                One = 1,
+                #[num_enum(code(table = 2), until = 3)]
+                Two,
+                #[num_enum(skip, code(table = 3))]
+                Three,
+            }",
+            &["IntoPrimitive", "TryFromPrimitive", "CodeTables"],
+        );
+        assert_safe(
+            "#[num_enum(crate = ::num_enum, ffi_export(prefix = \"opcode\"))]
+            #[repr(u8)]
+            enum Opcode {
+                Nop,
+                #[num_enum(alternatives = [0x11])]
+                Add = 0x10,
+            }",
+            &["TryFromPrimitive"],
+        );
+        assert_safe(
+            "#[num_enum(crate = ::num_enum, value_eq)]
+            #[repr(i32)]
+            enum CatchAll {
+                MinusOne = -1,
+                Zero,
+                #[num_enum(catch_all)]
+                Other(i32),
+            }",
+            &["IntoPrimitive", "FromPrimitive", "EnumMetadata"],
+        );
+        assert_safe(
+            "#[num_enum(crate = ::num_enum)]
+            #[repr(u8)]
+            enum Fallback {
+                Zero,
+                #[num_enum(fallback)]
+                Unknown,
+            }",
+            &["FromPrimitive"],
+        );
+        assert_safe(
+            "#[num_enum(crate = ::num_enum)]
+            #[repr(u8)]
+            enum Message {
+                Ping { id: u32 } = 1,
+                Move(i16, i16),
+                Close = 10,
+            }",
+            &["IntoPrimitive", "TaggedPayload"],
+        );
+    }
+}
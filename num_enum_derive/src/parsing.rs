@@ -1,28 +1,63 @@
-use crate::enum_attributes::ErrorTypeAttribute;
+use crate::enum_attributes::{
+    ConvertAttribute, DeprecatedValueWarningAttribute, ErrorTypeAttribute, SubsetDefinition,
+};
 use crate::utils::die;
-use crate::variant_attributes::{NumEnumVariantAttributeItem, NumEnumVariantAttributes};
+use crate::variant_attributes::{
+    NumEnumVariantAttributeItem, NumEnumVariantAttributes, VariantCode, VariantMaskAttribute,
+    VariantValueAttribute,
+};
 use proc_macro2::Span;
-use quote::{format_ident, ToTokens};
-use std::collections::BTreeSet;
+use quote::{format_ident, quote, ToTokens};
+use std::collections::{BTreeMap, BTreeSet};
 use syn::{
     parse::{Parse, ParseStream},
-    parse_quote, Attribute, Data, DeriveInput, Expr, ExprLit, ExprUnary, Fields, Ident, Lit,
-    LitInt, Meta, Path, Result, UnOp,
+    parse_quote, parse_quote_spanned,
+    punctuated::Punctuated,
+    spanned::Spanned,
+    Attribute, Data, DeriveInput, Expr, ExprLit, ExprUnary, Fields, Ident, Lit, LitInt, Member,
+    Meta, Path, Result, Token, Type, TypePath, UnOp, Visibility,
 };
 
 pub(crate) struct EnumInfo {
     pub(crate) name: Ident,
-    pub(crate) repr: Ident,
+    pub(crate) vis: Visibility,
+    /// The discriminant type, which is an integer type's ident unless the enum is `repr(C)`.
+    pub(crate) repr: Path,
     pub(crate) crate_path: Option<syn::Path>,
     pub(crate) variants: Vec<VariantInfo>,
     pub(crate) error_type_info: ErrorType,
+    pub(crate) num_traits: bool,
+    pub(crate) deprecated_value_warning: Option<DeprecatedValueWarningAttribute>,
+    /// Called with the enum's name and the value when `FromPrimitive` falls back to the default or
+    /// catch-all variant.
+    pub(crate) on_unknown: Option<Path>,
+    pub(crate) value_eq: bool,
+    /// The `core::num::NonZero*` type matching the repr, if the enum is `#[num_enum(nonzero)]`.
+    pub(crate) nonzero: Option<Ident>,
+    pub(crate) value_module: Option<Ident>,
+    /// The enums to generate conversions into, from `#[num_enum(convert(..))]`.
+    pub(crate) convert: Vec<ConvertAttribute>,
+    /// The enums of `#[num_enum(subset(..))]` attributes.
+    pub(crate) subsets: Vec<SubsetDefinition>,
+    /// The prefix of the `extern "C"` functions from `#[num_enum(ffi_export(prefix = ..))]`.
+    pub(crate) ffi_prefix: Option<String>,
+    /// Attributes which are only read by one derive, and so have no effect unless the enum also
+    /// derives it.
+    pub(crate) required_derives: Vec<RequiredDerive>,
+    /// Reported by derives which need to construct variants from just a primitive, if any variant
+    /// (other than a `catch_all` one) has fields.
+    non_unit_variant_error: Option<syn::Error>,
 }
 
 impl EnumInfo {
-    /// Returns whether the number of variants (ignoring defaults, catch-alls, etc) is the same as
-    /// the capacity of the repr.
+    /// Returns whether the number of variants (ignoring defaults, catch-alls, skipped variants,
+    /// etc) is the same as the capacity of the repr.
     pub(crate) fn is_naturally_exhaustive(&self) -> Result<bool> {
-        let repr_str = self.repr.to_string();
+        let Some(repr) = self.repr.get_ident() else {
+            // `c_int`'s size depends on the target, so can't be relied upon to be exhausted.
+            return Ok(false);
+        };
+        let repr_str = repr.to_string();
         if !repr_str.is_empty() {
             let suffix = repr_str
                 .strip_prefix('i')
@@ -36,7 +71,8 @@ impl EnumInfo {
                         v == self
                             .variants
                             .iter()
-                            .map(|v| v.alternative_values.len() + 1)
+                            .filter(|v| !v.is_skipped)
+                            .map(|v| v.alternative_values.len() + v.mask_value_count + 1)
                             .sum()
                     }));
                 }
@@ -52,6 +88,17 @@ impl EnumInfo {
             .map(|info| &info.ident)
     }
 
+    /// Returns the variant `FromPrimitive` converts unmatched values into, other than a catch-all.
+    ///
+    /// This is the `#[num_enum(fallback)]` variant if there is one, otherwise the default variant.
+    pub(crate) fn fallback(&self) -> Option<&Ident> {
+        self.variants
+            .iter()
+            .find(|info| info.is_fallback)
+            .map(|info| &info.ident)
+            .or_else(|| self.default())
+    }
+
     pub(crate) fn catch_all(&self) -> Option<&Ident> {
         self.variants
             .iter()
@@ -59,6 +106,16 @@ impl EnumInfo {
             .map(|info| &info.ident)
     }
 
+    /// Returns the catch-all variant, along with its only field.
+    pub(crate) fn catch_all_field(&self) -> Option<(&Ident, Member)> {
+        let variant = self.variants.iter().find(|info| info.is_catch_all)?;
+        let member = match &variant.fields.iter().next()?.ident {
+            Some(field_ident) => Member::Named(field_ident.clone()),
+            None => Member::Unnamed(0.into()),
+        };
+        Some((&variant.ident, member))
+    }
+
     pub(crate) fn variant_idents(&self) -> Vec<Ident> {
         self.variants
             .iter()
@@ -71,15 +128,119 @@ impl EnumInfo {
         self.variants
             .iter()
             .filter(|variant| !variant.is_catch_all)
-            .map(|info| {
-                let indices = 0..(info.alternative_values.len() + 1);
-                indices
-                    .map(|index| format_ident!("{}__num_enum_{}__", info.ident, index))
-                    .collect()
+            .map(VariantInfo::expression_idents)
+            .collect()
+    }
+
+    /// Returns the variants which primitives may be converted into by matching on their values,
+    /// which excludes catch-alls and `#[num_enum(skip)]` variants.
+    pub(crate) fn decoded_variants(&self) -> impl Iterator<Item = &VariantInfo> {
+        self.variants
+            .iter()
+            .filter(|variant| !variant.is_catch_all && !variant.is_skipped)
+    }
+
+    /// Like `variant_idents`, but only for the variants in `decoded_variants`.
+    pub(crate) fn decoded_variant_idents(&self) -> Vec<Ident> {
+        self.decoded_variants()
+            .map(|variant| variant.ident.clone())
+            .collect()
+    }
+
+    /// Like `expression_idents`, but only for the variants in `decoded_variants`.
+    pub(crate) fn decoded_expression_idents(&self) -> Vec<Vec<Ident>> {
+        self.decoded_variants()
+            .map(VariantInfo::expression_idents)
+            .collect()
+    }
+
+    /// Like `variant_expressions`, but only for the variants in `decoded_variants`.
+    pub(crate) fn decoded_variant_expressions(&self) -> Vec<Vec<Expr>> {
+        self.decoded_variants()
+            .map(|variant| variant.all_values().cloned().collect())
+            .collect()
+    }
+
+    /// Returns each variant with `#[num_enum(mask = .., value = ..)]`, along with its mask and
+    /// value.
+    pub(crate) fn masks(&self) -> Vec<(&Ident, &Expr, &Expr)> {
+        self.variants
+            .iter()
+            .filter_map(|variant| {
+                let (mask, value) = variant.mask.as_ref()?;
+                Some((&variant.ident, mask, value))
             })
             .collect()
     }
 
+    /// Returns the names of the tables in `#[num_enum(code(..))]` attributes, in the order they
+    /// first appear.
+    pub(crate) fn code_tables(&self) -> Vec<&Ident> {
+        let mut tables: Vec<&Ident> = vec![];
+        for variant in &self.variants {
+            for code in &variant.codes {
+                if !tables.contains(&&code.table) {
+                    tables.push(&code.table);
+                }
+            }
+        }
+        tables
+    }
+
+    pub(crate) fn has_versioned_variants(&self) -> bool {
+        self.variants
+            .iter()
+            .any(|variant| variant.since.is_some() || variant.until.is_some())
+    }
+
+    /// Returns the first variant marked `#[num_enum(skip(strict))]`, if any.
+    pub(crate) fn strictly_skipped(&self) -> Option<&Ident> {
+        self.variants
+            .iter()
+            .find(|info| info.is_strictly_skipped)
+            .map(|info| &info.ident)
+    }
+
+    pub(crate) fn has_default(&self) -> bool {
+        self.default().is_some()
+    }
+
+    pub(crate) fn has_fallback(&self) -> bool {
+        self.variants.iter().any(|info| info.is_fallback)
+    }
+
+    pub(crate) fn has_catch_all(&self) -> bool {
+        self.catch_all().is_some()
+    }
+
+    pub(crate) fn has_non_unit_variants(&self) -> bool {
+        self.non_unit_variant_error.is_some()
+    }
+
+    pub(crate) fn require_unit_variants(&self) -> Result<()> {
+        match &self.non_unit_variant_error {
+            Some(err) => Err(err.clone()),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns whether any variant has alternatives, including those matched by a mask.
+    pub(crate) fn has_alternatives(&self) -> bool {
+        self.variants
+            .iter()
+            .any(|variant| !variant.alternative_values.is_empty() || variant.mask.is_some())
+    }
+
+    /// Returns the canonical discriminant expression of each variant, ignoring alternatives and
+    /// catch-alls.
+    pub(crate) fn canonical_expressions(&self) -> Vec<Expr> {
+        self.variants
+            .iter()
+            .filter(|variant| !variant.is_catch_all)
+            .map(|variant| variant.canonical_value.clone())
+            .collect()
+    }
+
     pub(crate) fn variant_expressions(&self) -> Vec<Vec<Expr>> {
         self.variants
             .iter()
@@ -90,29 +251,32 @@ impl EnumInfo {
 
     fn parse_attrs<Attrs: Iterator<Item = Attribute>>(
         attrs: Attrs,
-    ) -> Result<(Ident, crate::enum_attributes::Attributes)> {
+    ) -> Result<(Path, crate::enum_attributes::Attributes)> {
         let mut maybe_repr = None;
+        let mut repr_c = None;
         let mut attributes = crate::enum_attributes::Attributes::default();
         for attr in attrs {
             if let Meta::List(meta_list) = &attr.meta {
                 if let Some(ident) = meta_list.path.get_ident() {
                     if ident == "repr" {
-                        let mut nested = meta_list.tokens.clone().into_iter();
-                        let repr_tree = match (nested.next(), nested.next()) {
-                            (Some(repr_tree), None) => repr_tree,
-                            _ => die!(attr =>
-                                "Expected exactly one `repr` argument"
-                            ),
-                        };
-                        let repr_ident: Ident = parse_quote! {
-                            #repr_tree
-                        };
-                        if repr_ident == "C" {
-                            die!(repr_ident =>
-                                "repr(C) doesn't have a well defined size"
-                            );
-                        } else {
-                            maybe_repr = Some(repr_ident);
+                        let hints =
+                            attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
+                        for hint in hints {
+                            // Hints which don't affect the discriminant type (e.g. `align(N)`) are
+                            // ignored, and invalid ones are left for rustc to report.
+                            let Meta::Path(path) = hint else {
+                                continue;
+                            };
+                            if path.is_ident("C") {
+                                repr_c = Some(path);
+                            } else if INTEGER_TYPES.iter().any(|ty| path.is_ident(ty)) {
+                                if maybe_repr.is_some() {
+                                    die!(path =>
+                                        "Expected at most one integer type in `repr` attributes"
+                                    );
+                                }
+                                maybe_repr = Some(path);
+                            }
                         }
                     } else if ident == "num_enum" {
                         let new_attributes =
@@ -122,10 +286,17 @@ impl EnumInfo {
                 }
             }
         }
-        if maybe_repr.is_none() {
-            die!("Missing `#[repr({Integer})]` attribute");
+        match (maybe_repr, repr_c) {
+            (Some(repr), _) => Ok((repr, attributes)),
+            // rustc sizes a `repr(C)` enum like the target's C compiler would, which is as a
+            // `c_int` on nearly all targets. Where it isn't, the transmutes we generate will fail
+            // to compile, rather than misbehaving.
+            (None, Some(c)) => Ok((
+                parse_quote_spanned! {c.span()=> ::core::ffi::c_int },
+                attributes,
+            )),
+            (None, None) => die!("Missing `#[repr({Integer})]` attribute"),
         }
-        Ok((maybe_repr.unwrap(), attributes))
     }
 }
 
@@ -134,6 +305,7 @@ impl Parse for EnumInfo {
         Ok({
             let input: DeriveInput = input.parse()?;
             let name = input.ident;
+            let vis = input.vis;
             let data = match input.data {
                 Data::Enum(data) => data,
                 Data::Union(data) => die!(data.union_token => "Expected enum but found union"),
@@ -142,14 +314,87 @@ impl Parse for EnumInfo {
 
             let (repr, attributes) = Self::parse_attrs(input.attrs.into_iter())?;
             let crate_path = attributes.crate_path.clone().map(|k| k.path);
+            let num_traits = attributes.num_traits.is_some();
+            let deprecated_value_warning = attributes.deprecated_value_warning.clone();
+            let on_unknown = attributes.on_unknown.clone().map(|attr| attr.path);
+            let value_eq = attributes.value_eq.is_some();
+            let value_module = attributes.value_module.clone().map(|attr| attr.ident);
+            let mut required_derives = vec![];
+            if let Some(on_unknown) = &attributes.on_unknown {
+                required_derives.push(RequiredDerive {
+                    attribute: "on_unknown",
+                    span: on_unknown.span,
+                    derive: Derive::FromPrimitive,
+                });
+            }
+            if let Some(value_eq) = &attributes.value_eq {
+                required_derives.push(RequiredDerive {
+                    attribute: "value_eq",
+                    span: value_eq.span,
+                    derive: Derive::IntoPrimitive,
+                });
+            }
+            for convert in &attributes.convert {
+                required_derives.push(RequiredDerive {
+                    attribute: "convert",
+                    span: convert.span,
+                    derive: Derive::IntoPrimitive,
+                });
+            }
+            for subset in &attributes.subsets {
+                required_derives.push(RequiredDerive {
+                    attribute: "subset",
+                    span: subset.name.span(),
+                    derive: Derive::Subsets,
+                });
+            }
+            if let Some(ffi_export) = &attributes.ffi_export {
+                required_derives.push(RequiredDerive {
+                    attribute: "ffi_export",
+                    span: ffi_export.span,
+                    derive: Derive::TryFromPrimitive,
+                });
+            }
+            if let Some(value_module) = &attributes.value_module {
+                required_derives.push(RequiredDerive {
+                    attribute: "value_module",
+                    span: value_module.span,
+                    derive: Derive::ValueConstants,
+                });
+            }
+            let nonzero =
+                match &attributes.nonzero {
+                    Some(nonzero) => match repr.get_ident() {
+                        Some(repr) => {
+                            let repr = repr.to_string();
+                            Some(format_ident!(
+                                "NonZero{}{}",
+                                repr[..1].to_uppercase(),
+                                &repr[1..]
+                            ))
+                        }
+                        None => return Err(syn::Error::new(
+                            nonzero.span,
+                            "`#[num_enum(nonzero)]` requires the enum's repr to be an integer type",
+                        )),
+                    },
+                    None => None,
+                };
 
             let mut variants: Vec<VariantInfo> = vec![];
             let mut has_default_variant: bool = false;
             let mut has_catch_all_variant: bool = false;
+            let mut has_fallback_variant: bool = false;
+            let mut non_unit_variant_error = None;
 
             // Vec to keep track of the used discriminants and alt values.
             let mut discriminant_int_val_set = BTreeSet::new();
+            // Likewise for the literal codes in each `#[num_enum(code(..))]` table.
+            let mut code_int_val_sets: BTreeMap<String, BTreeSet<i128>> = BTreeMap::new();
 
+            // rustc removes variants whose `#[cfg]`s are disabled before invoking derives, so every
+            // variant seen here exists, and any `#[cfg]` attributes left on them are enabled. This
+            // makes both implicit discriminants and exhaustiveness checks follow the active cfg.
             let mut next_discriminant = literal(0);
             for variant in data.variants.into_iter() {
                 let ident = variant.ident.clone();
@@ -168,6 +413,15 @@ impl Parse for EnumInfo {
                 // keep track of whether we encountered such an attribute:
                 let mut is_default: bool = false;
                 let mut is_catch_all: bool = false;
+                let mut fallback_keyword = None;
+                let mut skip_attribute = None;
+                let mut mask_attribute: Option<VariantMaskAttribute> = None;
+                let mut value_attribute: Option<VariantValueAttribute> = None;
+                let mut code_keyword = None;
+                let mut since: Option<u32> = None;
+                let mut until: Option<(u32, LitInt)> = None;
+                let mut codes: Vec<VariantCode> = vec![];
+                let mut maps_to: Vec<Path> = vec![];
 
                 for attribute in &variant.attrs {
                     if attribute.path().is_ident("default") {
@@ -211,6 +465,14 @@ impl Parse for EnumInfo {
                                                 die!(catch_all.keyword =>
                                                     "Attribute `catch_all` is mutually exclusive with `default`"
                                                 );
+                                            } else if has_fallback_variant {
+                                                die!(catch_all.keyword =>
+                                                    "Attribute `catch_all` is mutually exclusive with `fallback`"
+                                                );
+                                            } else if nonzero.is_some() {
+                                                die!(catch_all.keyword =>
+                                                    "Attribute `catch_all` can't be used on `#[num_enum(nonzero)]` enums, as it may hold zero"
+                                                );
                                             }
 
                                             match variant
@@ -219,20 +481,134 @@ impl Parse for EnumInfo {
                                                 .collect::<Vec<_>>()
                                                 .as_slice()
                                             {
-                                                [syn::Field {
-                                                    ty: syn::Type::Path(syn::TypePath { path, .. }),
-                                                    ..
-                                                }] if path.is_ident(&repr) => {
+                                                [field]
+                                                    if !is_other_integer_type(&repr, &field.ty) =>
+                                                {
                                                     is_catch_all = true;
                                                     has_catch_all_variant = true;
                                                 }
                                                 _ => {
                                                     die!(catch_all.keyword =>
-                                                        "Variant with `catch_all` must be a tuple with exactly 1 field matching the repr type"
+                                                        "Variant with `catch_all` must have exactly 1 field, of the repr type or a type which converts to and from it"
                                                     );
                                                 }
                                             }
                                         }
+                                        NumEnumVariantAttributeItem::Fallback(fallback) => {
+                                            if has_fallback_variant {
+                                                die!(fallback.keyword =>
+                                                    "Multiple variants marked `#[num_enum(fallback)]` found"
+                                                );
+                                            } else if has_catch_all_variant {
+                                                die!(fallback.keyword =>
+                                                    "Attribute `fallback` is mutually exclusive with `catch_all`"
+                                                );
+                                            }
+                                            fallback_keyword = Some(fallback.keyword);
+                                            has_fallback_variant = true;
+                                        }
+                                        NumEnumVariantAttributeItem::Skip(skip) => {
+                                            if skip_attribute.is_some() {
+                                                die!(skip.keyword =>
+                                                    "Multiple `#[num_enum(skip)]` attributes found on the same variant"
+                                                );
+                                            }
+                                            skip_attribute = Some(skip);
+                                        }
+                                        NumEnumVariantAttributeItem::Mask(mask) => {
+                                            if mask_attribute.is_some() {
+                                                die!(mask.keyword =>
+                                                    "Multiple `mask` attributes found on the same variant"
+                                                );
+                                            }
+                                            mask_attribute = Some(mask);
+                                        }
+                                        NumEnumVariantAttributeItem::Value(value) => {
+                                            if value_attribute.is_some() {
+                                                die!(value.keyword =>
+                                                    "Multiple `value` attributes found on the same variant"
+                                                );
+                                            }
+                                            value_attribute = Some(value);
+                                        }
+                                        NumEnumVariantAttributeItem::Code(code) => {
+                                            // One error is enough, however many variants have codes.
+                                            if !required_derives
+                                                .iter()
+                                                .any(|required| required.attribute == "code")
+                                            {
+                                                required_derives.push(RequiredDerive {
+                                                    attribute: "code",
+                                                    span: code.keyword.span,
+                                                    derive: Derive::CodeTables,
+                                                });
+                                            }
+                                            for table_code in code.tables {
+                                                if codes.iter().any(|existing| {
+                                                    existing.table == table_code.table
+                                                }) {
+                                                    die!(&table_code.table => format!(
+                                                        "Multiple codes in table `{}` found on the same variant",
+                                                        table_code.table
+                                                    ));
+                                                }
+                                                codes.push(table_code);
+                                            }
+                                            code_keyword = Some(code.keyword);
+                                        }
+                                        NumEnumVariantAttributeItem::Since(attr) => {
+                                            if since.is_some() {
+                                                die!(attr.keyword =>
+                                                    "Multiple `since` attributes found on the same variant"
+                                                );
+                                            }
+                                            since = Some(attr.version.base10_parse()?);
+                                            required_derives.push(RequiredDerive {
+                                                attribute: "since",
+                                                span: attr.keyword.span,
+                                                derive: Derive::TryFromPrimitive,
+                                            });
+                                        }
+                                        NumEnumVariantAttributeItem::Until(attr) => {
+                                            if until.is_some() {
+                                                die!(attr.keyword =>
+                                                    "Multiple `until` attributes found on the same variant"
+                                                );
+                                            }
+                                            until =
+                                                Some((attr.version.base10_parse()?, attr.version));
+                                            required_derives.push(RequiredDerive {
+                                                attribute: "until",
+                                                span: attr.keyword.span,
+                                                derive: Derive::TryFromPrimitive,
+                                            });
+                                        }
+                                        NumEnumVariantAttributeItem::MapsTo(attr) => {
+                                            // Targets must be spelled the same way in both attributes.
+                                            let target =
+                                                enum_of_variant_path(&attr.path).filter(|target| {
+                                                    attributes
+                                                        .convert
+                                                        .iter()
+                                                        .any(|convert| convert.to == *target)
+                                                });
+                                            let Some(target) = target else {
+                                                die!(&attr.path => format!(
+                                                    "`{}` isn't a variant of an enum in a `#[num_enum(convert(to = ..))]` attribute",
+                                                    attr.path.to_token_stream()
+                                                ));
+                                            };
+                                            if maps_to.iter().any(|existing| {
+                                                enum_of_variant_path(existing).as_ref()
+                                                    == Some(&target)
+                                            }) {
+                                                die!(attr.keyword => format!(
+                                                    "Multiple `maps_to` attributes for `{}` found on the same variant",
+                                                    target.to_token_stream()
+                                                ));
+                                            }
+                                            maps_to.push(attr.path);
+                                        }
                                         NumEnumVariantAttributeItem::Alternatives(alternatives) => {
                                             raw_alternative_values.extend(alternatives.expressions);
                                             alt_attr_ref.push(attribute);
@@ -260,10 +636,89 @@ impl Parse for EnumInfo {
                     }
                 }
 
-                if !is_catch_all {
+                if let Some(fallback_keyword) = fallback_keyword {
+                    if is_default {
+                        die!(fallback_keyword =>
+                            "Attribute `fallback` is redundant on the `default` variant, which `FromPrimitive` already falls back to"
+                        );
+                    }
+                }
+
+                if let Some(skip) = &skip_attribute {
+                    let other_attribute = if is_default {
+                        Some("default")
+                    } else if is_catch_all {
+                        Some("catch_all")
+                    } else if fallback_keyword.is_some() {
+                        Some("fallback")
+                    } else if !raw_alternative_values.is_empty() {
+                        Some("alternatives")
+                    } else if mask_attribute.is_some() {
+                        Some("mask")
+                    } else {
+                        None
+                    };
+                    if let Some(other_attribute) = other_attribute {
+                        die!(skip.keyword => format!(
+                            "Attribute `skip` is mutually exclusive with `{}`, as skipped variants are never converted into from a primitive",
+                            other_attribute
+                        ));
+                    }
+                }
+
+                let mask = match (mask_attribute, value_attribute) {
+                    (Some(mask), Some(value)) => {
+                        if is_catch_all {
+                            die!(mask.keyword =>
+                                "Attribute `mask` can't be used on the `catch_all` variant, which already matches every unknown value"
+                            );
+                        }
+                        Some((mask, value))
+                    }
+                    (Some(mask), None) => die!(mask.keyword =>
+                        "Attribute `mask` requires a `value` for the masked bits to equal"
+                    ),
+                    (None, Some(value)) => die!(value.keyword =>
+                        "Attribute `value` requires a `mask` to select the bits to compare"
+                    ),
+                    (None, None) => None,
+                };
+
+                if let (Some(since), Some((until, until_lit))) = (since, &until) {
+                    if *until < since {
+                        die!(until_lit => "`until` must not be an earlier version than `since`");
+                    }
+                }
+
+                if let Some(code_keyword) = code_keyword {
+                    if is_catch_all {
+                        die!(code_keyword =>
+                            "Attribute `code` can't be used on the `catch_all` variant, which holds the code it was converted from"
+                        );
+                    }
+                }
+
+                // Like discriminants, only literal codes can be checked for collisions here.
+                for table_code in &codes {
+                    let code_int_val_set = code_int_val_sets
+                        .entry(table_code.table.to_string())
+                        .or_default();
+                    for code in &table_code.codes {
+                        if let DiscriminantValue::Literal(code_int) = parse_discriminant(code)? {
+                            if !code_int_val_set.insert(code_int) {
+                                die!(code => format!(
+                                    "The code '{}' in table `{}` collides with another code in the table",
+                                    code_int, table_code.table
+                                ));
+                            }
+                        }
+                    }
+                }
+
+                if !is_catch_all && non_unit_variant_error.is_none() {
                     match &variant.fields {
                         Fields::Named(_) | Fields::Unnamed(_) => {
-                            die!(variant => format!("`{}` only supports unit variants (with no associated data), but `{}::{}` was not a unit variant.", crate_path_as_string(&get_crate_path(crate_path))?, name, ident));
+                            non_unit_variant_error = Some(syn::Error::new_spanned(&variant, format!("`{}` only supports unit variants (with no associated data), but `{}::{}` was not a unit variant.", crate_path_as_string(&get_crate_path(crate_path.clone()))?, name, ident)));
                         }
                         Fields::Unit => {}
                     }
@@ -271,6 +726,17 @@ impl Parse for EnumInfo {
 
                 let discriminant_value = parse_discriminant(&discriminant)?;
 
+                // Non-literal discriminants are checked by assertions in the generated code.
+                if nonzero.is_some() {
+                    if let DiscriminantValue::Literal(0) = discriminant_value {
+                        let message = "`#[num_enum(nonzero)]` enums can't have a variant with the discriminant 0";
+                        match &variant.discriminant {
+                            Some((_, expr)) => die!(expr => message),
+                            None => die!(ident => message),
+                        }
+                    }
+                }
+
                 // Check for collision.
                 // We can't do const evaluation, or even compare arbitrary Exprs,
                 // so unfortunately we can't check for duplicates.
@@ -293,6 +759,9 @@ impl Parse for EnumInfo {
                     }
                 }
 
+                // Kept to tell a mask overlapping this variant's own alternatives apart from one
+                // overlapping another variant's values.
+                let mut own_alternative_int_values = BTreeSet::new();
                 if !flattened_alternative_values.is_empty() {
                     let alternate_int_values = flattened_alternative_values
                         .into_iter()
@@ -329,6 +798,12 @@ impl Parse for EnumInfo {
                         }
                     }
 
+                    if nonzero.is_some() {
+                        if let Some(index) = alternate_int_values.iter().position(|&x| x == 0) {
+                            die!(&flattened_raw_alternative_values[index] => "`#[num_enum(nonzero)]` enums can't have 0 as an alternative value");
+                        }
+                    }
+
                     // Search for duplicates, the vec is sorted. Warn about them.
                     if (1..sorted_alternate_int_values.len()).any(|i| {
                         sorted_alternate_int_values[i] == sorted_alternate_int_values[i - 1]
@@ -355,9 +830,45 @@ impl Parse for EnumInfo {
                         .collect();
 
                     // Add the alternative values to the the set to keep track.
+                    own_alternative_int_values.extend(sorted_alternate_int_values.iter().copied());
                     discriminant_int_val_set.extend(sorted_alternate_int_values);
                 }
 
+                // Deal with the values matched by a mask, which are expanded so that they can be
+                // checked against other variants' values, though the generated code tests the mask.
+                let mut mask_value_count = 0;
+                let mask = match mask {
+                    Some((mask, value)) => {
+                        let (mask_int, value_int, masked_values) =
+                            expand_mask(&repr, &mask, &value)?;
+                        for masked_value in &masked_values {
+                            if own_alternative_int_values.contains(masked_value) {
+                                die!(&mask.expr => format!("'{}' matched by the mask is already one of this variant's alternatives", masked_value));
+                            }
+                            if discriminant_int_val_set.contains(masked_value) {
+                                die!(&mask.expr => format!("'{}' matched by the mask is already attributed to a previous variant", masked_value));
+                            }
+                        }
+                        if nonzero.is_some() && masked_values.contains(&0) {
+                            die!(&value.expr => "`#[num_enum(nonzero)]` enums can't match 0 with a mask");
+                        }
+                        // The variant's own discriminant may be matched by the mask, in which case
+                        // it mustn't be counted twice. Non-literal discriminants are assumed to be
+                        // matched, so that exhaustiveness is never overestimated.
+                        mask_value_count = match discriminant_value {
+                            DiscriminantValue::Literal(canonical_value_int)
+                                if !masked_values.contains(&canonical_value_int) =>
+                            {
+                                masked_values.len()
+                            }
+                            _ => masked_values.len() - 1,
+                        };
+                        discriminant_int_val_set.extend(masked_values);
+                        Some((literal(mask_int), literal(value_int)))
+                    }
+                    None => None,
+                };
+
                 // Add the current discriminant to the the set to keep track.
                 if let DiscriminantValue::Literal(canonical_value_int) = discriminant_value {
                     discriminant_int_val_set.insert(canonical_value_int);
@@ -367,8 +878,28 @@ impl Parse for EnumInfo {
                     ident,
                     is_default,
                     is_catch_all,
+                    is_fallback: fallback_keyword.is_some(),
+                    is_skipped: skip_attribute.is_some(),
+                    is_strictly_skipped: skip_attribute.is_some_and(|skip| skip.strict),
+                    is_deprecated: variant
+                        .attrs
+                        .iter()
+                        .any(|attribute| attribute.path().is_ident("deprecated")),
                     canonical_value: discriminant,
                     alternative_values: flattened_raw_alternative_values,
+                    mask,
+                    mask_value_count,
+                    codes,
+                    since,
+                    until: until.map(|(until, _)| until),
+                    maps_to,
+                    fields: variant.fields.clone(),
+                    num_enum_attrs: variant
+                        .attrs
+                        .iter()
+                        .filter(|attribute| attribute.path().is_ident("num_enum"))
+                        .cloned()
+                        .collect(),
                 });
 
                 // Get the next value for the discriminant.
@@ -396,15 +927,72 @@ impl Parse for EnumInfo {
 
             EnumInfo {
                 name,
+                vis,
                 repr,
                 variants,
                 crate_path,
                 error_type_info,
+                num_traits,
+                deprecated_value_warning,
+                on_unknown,
+                value_eq,
+                nonzero,
+                value_module,
+                convert: attributes.convert,
+                subsets: attributes.subsets,
+                ffi_prefix: attributes.ffi_export.map(|attr| attr.prefix.value()),
+                required_derives,
+                non_unit_variant_error,
             }
         })
     }
 }
 
+/// Returns the path of the enum which the variant at `path` belongs to.
+pub(crate) fn enum_of_variant_path(path: &Path) -> Option<Path> {
+    if path.segments.len() < 2 {
+        return None;
+    }
+    let mut enum_path = path.clone();
+    enum_path.segments.pop();
+    enum_path.segments.pop_punct();
+    Some(enum_path)
+}
+
+const INTEGER_TYPES: &[&str] = &[
+    "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize",
+];
+
+/// Returns whether `ty` is a primitive integer type other than the repr, which can't be the payload
+/// of a catch-all variant, as it wouldn't round-trip through the repr.
+///
+/// Any other type is assumed to convert to and from the repr, which rustc checks when the
+/// generated conversions are compiled.
+fn is_other_integer_type(repr: &Path, ty: &Type) -> bool {
+    let Type::Path(TypePath { qself: None, path }) = ty else {
+        return false;
+    };
+    let segments: Vec<String> = path
+        .segments
+        .iter()
+        .map(|segment| segment.ident.to_string())
+        .collect();
+    let ty = match segments.as_slice() {
+        [ty] if path.leading_colon.is_none() => ty,
+        [krate, primitive, ty]
+            if (krate == "core" || krate == "std") && primitive == "primitive" =>
+        {
+            ty
+        }
+        _ => return false,
+    };
+    let repr = repr
+        .segments
+        .last()
+        .map(|segment| segment.ident.to_string());
+    INTEGER_TYPES.contains(&ty.as_str()) && repr.as_ref() != Some(ty)
+}
+
 fn literal(i: i128) -> Expr {
     Expr::Lit(ExprLit {
         lit: Lit::Int(LitInt::new(&i.to_string(), Span::call_site())),
@@ -412,6 +1000,66 @@ fn literal(i: i128) -> Expr {
     })
 }
 
+/// The most bits a `#[num_enum(mask = ..)]` may leave unmasked, as every value it matches is
+/// expanded to check for overlaps.
+const MAX_UNMASKED_BITS: u32 = 16;
+
+/// Returns the mask and value of a `#[num_enum(mask = .., value = ..)]` attribute, normalized to
+/// values of the repr type, along with every value they match, in ascending order.
+fn expand_mask(
+    repr: &Path,
+    mask: &VariantMaskAttribute,
+    value: &VariantValueAttribute,
+) -> Result<(i128, i128, Vec<i128>)> {
+    let repr_str = repr.get_ident().map(Ident::to_string).unwrap_or_default();
+    let Some(bits) = repr_str.get(1..).and_then(|bits| bits.parse::<u32>().ok()) else {
+        die!(mask.keyword => "Attribute `mask` requires the enum's repr to be a fixed-size integer type");
+    };
+    let is_signed = repr_str.starts_with('i');
+
+    let DiscriminantValue::Literal(mask_int) = parse_discriminant(&mask.expr)? else {
+        die!(&mask.expr => "Only literals are allowed as num_enum masks");
+    };
+    let DiscriminantValue::Literal(value_int) = parse_discriminant(&value.expr)? else {
+        die!(&value.expr => "Only literals are allowed as num_enum masked values");
+    };
+
+    // Work with the bit patterns of the repr, so that e.g. `mask = 0xF0` works for `i8`.
+    let all_bits = u128::MAX >> (128 - bits);
+    let mask_bits = mask_int as u128 & all_bits;
+    let value_bits = value_int as u128 & all_bits;
+    if value_bits & !mask_bits != 0 {
+        die!(&value.expr => "The masked value has bits set outside of the mask, so would never match");
+    }
+    let unmasked_bits = all_bits & !mask_bits;
+    if unmasked_bits.count_ones() > MAX_UNMASKED_BITS {
+        die!(&mask.expr => format!(
+            "The mask must cover all but at most {} bits of the repr, so that the values it matches can be checked against other variants",
+            MAX_UNMASKED_BITS
+        ));
+    }
+    let to_repr_value = |bit_pattern: u128| {
+        if is_signed && bits < 128 && bit_pattern >> (bits - 1) == 1 {
+            bit_pattern as i128 - (1i128 << bits)
+        } else {
+            bit_pattern as i128
+        }
+    };
+
+    // Enumerate every combination of the unmasked bits.
+    let mut values = Vec::with_capacity(1 << unmasked_bits.count_ones());
+    let mut unmasked_value = unmasked_bits;
+    loop {
+        values.push(to_repr_value(value_bits | unmasked_value));
+        if unmasked_value == 0 {
+            break;
+        }
+        unmasked_value = (unmasked_value - 1) & unmasked_bits;
+    }
+    values.sort_unstable();
+    Ok((to_repr_value(mask_bits), to_repr_value(value_bits), values))
+}
+
 enum DiscriminantValue {
     Literal(i128),
     Expr(Expr),
@@ -504,17 +1152,139 @@ fn parse_alternative_values(val_expr: &Expr) -> Result<Vec<DiscriminantValue>> {
 }
 
 pub(crate) struct VariantInfo {
-    ident: Ident,
+    pub(crate) ident: Ident,
     is_default: bool,
-    is_catch_all: bool,
-    canonical_value: Expr,
-    alternative_values: Vec<Expr>,
+    pub(crate) is_catch_all: bool,
+    is_fallback: bool,
+    /// Whether the variant is marked `#[num_enum(skip)]`, so is never converted into from a
+    /// primitive.
+    is_skipped: bool,
+    /// Whether the variant is marked `#[num_enum(skip(strict))]`, so also can't be converted into
+    /// a primitive.
+    is_strictly_skipped: bool,
+    pub(crate) is_deprecated: bool,
+    pub(crate) canonical_value: Expr,
+    pub(crate) alternative_values: Vec<Expr>,
+    /// The `(mask, value)` of `#[num_enum(mask = .., value = ..)]`, as literals of the repr type.
+    mask: Option<(Expr, Expr)>,
+    /// How many values the mask matches, other than the canonical discriminant.
+    mask_value_count: usize,
+    /// The variant's codes in each `#[num_enum(code(..))]` table.
+    pub(crate) codes: Vec<VariantCode>,
+    /// The first protocol version the variant is valid in, from `#[num_enum(since = ..)]`.
+    pub(crate) since: Option<u32>,
+    /// The last protocol version the variant is valid in, from `#[num_enum(until = ..)]`.
+    pub(crate) until: Option<u32>,
+    /// The variants of `#[num_enum(convert(..))]` target enums this variant converts into, from
+    /// `#[num_enum(maps_to = ..)]`.
+    pub(crate) maps_to: Vec<Path>,
+    pub(crate) fields: Fields,
+    /// The variant's `#[num_enum(...)]` attributes, for derives which generate a similar enum.
+    pub(crate) num_enum_attrs: Vec<Attribute>,
 }
 
 impl VariantInfo {
+    fn expression_idents(&self) -> Vec<Ident> {
+        let indices = 0..(self.alternative_values.len() + 1);
+        indices
+            .map(|index| format_ident!("{}__num_enum_{}__", self.ident, index))
+            .collect()
+    }
+
     fn all_values(&self) -> impl Iterator<Item = &Expr> {
         ::core::iter::once(&self.canonical_value).chain(self.alternative_values.iter())
     }
+
+    /// Returns `#[num_enum(..)]` attributes which make a variant of an enum generated by `derive`
+    /// convert from the same values as this variant: its alternatives and mask, and whether it is
+    /// skipped, or is the default or fallback variant.
+    ///
+    /// Variants with other attributes are rejected, as they only apply to the original enum.
+    pub(crate) fn value_attrs(&self, derive: &str) -> Result<proc_macro2::TokenStream> {
+        for attribute in &self.num_enum_attrs {
+            let attributes: NumEnumVariantAttributes = attribute.parse_args()?;
+            for item in attributes.items {
+                let (keyword, name) = match item {
+                    NumEnumVariantAttributeItem::CatchAll(attr) => {
+                        (attr.keyword.into_token_stream(), "catch_all")
+                    }
+                    NumEnumVariantAttributeItem::Skip(attr) if attr.strict => {
+                        (attr.keyword.into_token_stream(), "skip(strict)")
+                    }
+                    NumEnumVariantAttributeItem::Code(attr) => {
+                        (attr.keyword.into_token_stream(), "code(..)")
+                    }
+                    NumEnumVariantAttributeItem::Since(attr) => {
+                        (attr.keyword.into_token_stream(), "since = ..")
+                    }
+                    NumEnumVariantAttributeItem::Until(attr) => {
+                        (attr.keyword.into_token_stream(), "until = ..")
+                    }
+                    NumEnumVariantAttributeItem::MapsTo(attr) => {
+                        (attr.keyword.into_token_stream(), "maps_to = ..")
+                    }
+                    _ => continue,
+                };
+                die!(keyword => format!(
+                    "#[derive(num_enum::{})] does not support variants marked `#[num_enum({})]`",
+                    derive, name
+                ));
+            }
+        }
+
+        let alternatives = (!self.alternative_values.is_empty()).then(|| {
+            let alternative_values = &self.alternative_values;
+            quote! { #[num_enum(alternatives = [#(#alternative_values),*])] }
+        });
+        let mask = self.mask.as_ref().map(|(mask, value)| {
+            quote! { #[num_enum(mask = #mask, value = #value)] }
+        });
+        let skip = self.is_skipped.then(|| quote! { #[num_enum(skip)] });
+        let default = self.is_default.then(|| quote! { #[num_enum(default)] });
+        let fallback = self.is_fallback.then(|| quote! { #[num_enum(fallback)] });
+        Ok(quote! {
+            #alternatives
+            #mask
+            #skip
+            #default
+            #fallback
+        })
+    }
+}
+
+/// An attribute which only has an effect if the enum also derives `derive`.
+///
+/// Derives can't see which other derives an enum has, so each derive asserts that the enum
+/// implements a trait which `derive` implements, rather than silently ignoring the attribute.
+pub(crate) struct RequiredDerive {
+    pub(crate) attribute: &'static str,
+    pub(crate) span: Span,
+    pub(crate) derive: Derive,
+}
+
+/// The derives which read attributes that no other derive reads.
+#[derive(Clone, Copy)]
+pub(crate) enum Derive {
+    IntoPrimitive,
+    FromPrimitive,
+    TryFromPrimitive,
+    ValueConstants,
+    CodeTables,
+    Subsets,
+}
+
+impl Derive {
+    /// The name of the derive, in `snake_case`.
+    pub(crate) fn snake_case_name(self) -> &'static str {
+        match self {
+            Self::IntoPrimitive => "into_primitive",
+            Self::FromPrimitive => "from_primitive",
+            Self::TryFromPrimitive => "try_from_primitive",
+            Self::ValueConstants => "value_constants",
+            Self::CodeTables => "code_tables",
+            Self::Subsets => "subsets",
+        }
+    }
 }
 
 pub(crate) struct ErrorType {
@@ -13,3 +13,62 @@ macro_rules! die {
 }
 
 pub(crate) use die;
+
+/// Converts a variant name like `AddImmediate` or `HTTPGet` to `ADD_IMMEDIATE` or `HTTP_GET`.
+pub(crate) fn screaming_snake_case(ident: &str) -> String {
+    let ident = ident.strip_prefix("r#").unwrap_or(ident);
+    let chars: Vec<char> = ident.chars().collect();
+    let mut result = String::with_capacity(ident.len() + 4);
+    for (index, &c) in chars.iter().enumerate() {
+        if c.is_uppercase() && index > 0 && chars[index - 1] != '_' {
+            let previous = chars[index - 1];
+            let next_is_lowercase = chars.get(index + 1).is_some_and(|next| next.is_lowercase());
+            if previous.is_lowercase()
+                || previous.is_numeric()
+                || (previous.is_uppercase() && next_is_lowercase)
+            {
+                result.push('_');
+            }
+        }
+        result.extend(c.to_uppercase());
+    }
+    result
+}
+
+/// Converts a name like `rtp_payload` to `RtpPayload`.
+pub(crate) fn upper_camel_case(ident: &str) -> String {
+    let ident = ident.strip_prefix("r#").unwrap_or(ident);
+    let mut result = String::with_capacity(ident.len());
+    for word in ident.split('_') {
+        let mut chars = word.chars();
+        if let Some(first) = chars.next() {
+            result.extend(first.to_uppercase());
+            result.extend(chars);
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use super::{screaming_snake_case, upper_camel_case};
+
+    #[test]
+    fn screaming_snake_case_conversions() {
+        assert_eq!(screaming_snake_case("Add"), "ADD");
+        assert_eq!(screaming_snake_case("AddImmediate"), "ADD_IMMEDIATE");
+        assert_eq!(screaming_snake_case("HTTPGet"), "HTTP_GET");
+        assert_eq!(screaming_snake_case("Utf8Text"), "UTF8_TEXT");
+        assert_eq!(screaming_snake_case("Snake_Case"), "SNAKE_CASE");
+        assert_eq!(screaming_snake_case("ALREADY"), "ALREADY");
+        assert_eq!(screaming_snake_case("r#Type"), "TYPE");
+    }
+
+    #[test]
+    fn upper_camel_case_conversions() {
+        assert_eq!(upper_camel_case("rtp"), "Rtp");
+        assert_eq!(upper_camel_case("mp4"), "Mp4");
+        assert_eq!(upper_camel_case("rtp_payload"), "RtpPayload");
+        assert_eq!(upper_camel_case("Mkv"), "Mkv");
+    }
+}
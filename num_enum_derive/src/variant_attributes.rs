@@ -6,7 +6,16 @@ use syn::{
 mod kw {
     syn::custom_keyword!(default);
     syn::custom_keyword!(catch_all);
+    syn::custom_keyword!(fallback);
     syn::custom_keyword!(alternatives);
+    syn::custom_keyword!(skip);
+    syn::custom_keyword!(strict);
+    syn::custom_keyword!(mask);
+    syn::custom_keyword!(value);
+    syn::custom_keyword!(code);
+    syn::custom_keyword!(since);
+    syn::custom_keyword!(until);
+    syn::custom_keyword!(maps_to);
 }
 
 pub(crate) struct NumEnumVariantAttributes {
@@ -24,7 +33,15 @@ impl Parse for NumEnumVariantAttributes {
 pub(crate) enum NumEnumVariantAttributeItem {
     Default(VariantDefaultAttribute),
     CatchAll(VariantCatchAllAttribute),
+    Fallback(VariantFallbackAttribute),
     Alternatives(VariantAlternativesAttribute),
+    Skip(VariantSkipAttribute),
+    Mask(VariantMaskAttribute),
+    Value(VariantValueAttribute),
+    Code(VariantCodeAttribute),
+    Since(VariantSinceAttribute),
+    Until(VariantUntilAttribute),
+    MapsTo(VariantMapsToAttribute),
 }
 
 impl Parse for NumEnumVariantAttributeItem {
@@ -34,8 +51,24 @@ impl Parse for NumEnumVariantAttributeItem {
             input.parse().map(Self::Default)
         } else if lookahead.peek(kw::catch_all) {
             input.parse().map(Self::CatchAll)
+        } else if lookahead.peek(kw::fallback) {
+            input.parse().map(Self::Fallback)
         } else if lookahead.peek(kw::alternatives) {
             input.parse().map(Self::Alternatives)
+        } else if lookahead.peek(kw::skip) {
+            input.parse().map(Self::Skip)
+        } else if lookahead.peek(kw::mask) {
+            input.parse().map(Self::Mask)
+        } else if lookahead.peek(kw::value) {
+            input.parse().map(Self::Value)
+        } else if lookahead.peek(kw::code) {
+            input.parse().map(Self::Code)
+        } else if lookahead.peek(kw::since) {
+            input.parse().map(Self::Since)
+        } else if lookahead.peek(kw::until) {
+            input.parse().map(Self::Until)
+        } else if lookahead.peek(kw::maps_to) {
+            input.parse().map(Self::MapsTo)
         } else {
             Err(lookahead.error())
         }
@@ -66,6 +99,173 @@ impl Parse for VariantCatchAllAttribute {
     }
 }
 
+pub(crate) struct VariantFallbackAttribute {
+    pub(crate) keyword: kw::fallback,
+}
+
+impl Parse for VariantFallbackAttribute {
+    fn parse(input: ParseStream) -> Result<Self> {
+        Ok(Self {
+            keyword: input.parse()?,
+        })
+    }
+}
+
+// Example: skip, or skip(strict)
+pub(crate) struct VariantSkipAttribute {
+    pub(crate) keyword: kw::skip,
+    pub(crate) strict: bool,
+}
+
+impl Parse for VariantSkipAttribute {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let keyword = input.parse()?;
+        let strict = if input.peek(syn::token::Paren) {
+            let content;
+            syn::parenthesized!(content in input);
+            content.parse::<kw::strict>()?;
+            true
+        } else {
+            false
+        };
+        Ok(Self { keyword, strict })
+    }
+}
+
+// Example: mask = 0xF0
+pub(crate) struct VariantMaskAttribute {
+    pub(crate) keyword: kw::mask,
+    pub(crate) expr: Expr,
+}
+
+impl Parse for VariantMaskAttribute {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let keyword = input.parse()?;
+        input.parse::<syn::Token![=]>()?;
+        Ok(Self {
+            keyword,
+            expr: input.parse()?,
+        })
+    }
+}
+
+// Example: value = 0x40
+pub(crate) struct VariantValueAttribute {
+    pub(crate) keyword: kw::value,
+    pub(crate) expr: Expr,
+}
+
+impl Parse for VariantValueAttribute {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let keyword = input.parse()?;
+        input.parse::<syn::Token![=]>()?;
+        Ok(Self {
+            keyword,
+            expr: input.parse()?,
+        })
+    }
+}
+
+// Example: code(rtp = 96, mp4 = [0x61, 0x62])
+pub(crate) struct VariantCodeAttribute {
+    pub(crate) keyword: kw::code,
+    pub(crate) tables: syn::punctuated::Punctuated<VariantCode, syn::Token![,]>,
+}
+
+impl Parse for VariantCodeAttribute {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let content;
+        let keyword = input.parse()?;
+        syn::parenthesized!(content in input);
+        Ok(Self {
+            keyword,
+            tables: content.parse_terminated(VariantCode::parse, syn::Token![,])?,
+        })
+    }
+}
+
+/// A variant's code in one table, followed by any alternative codes.
+pub(crate) struct VariantCode {
+    pub(crate) table: syn::Ident,
+    pub(crate) codes: Vec<Expr>,
+}
+
+impl Parse for VariantCode {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let table = input.parse()?;
+        input.parse::<syn::Token![=]>()?;
+        let codes = if input.peek(syn::token::Bracket) {
+            let content;
+            let bracket_token = syn::bracketed!(content in input);
+            let codes: Vec<Expr> = content
+                .parse_terminated(Expr::parse, syn::Token![,])?
+                .into_iter()
+                .collect();
+            if codes.is_empty() {
+                return Err(syn::Error::new(
+                    bracket_token.span.join(),
+                    "Expected at least one code",
+                ));
+            }
+            codes
+        } else {
+            vec![input.parse()?]
+        };
+        Ok(Self { table, codes })
+    }
+}
+
+// Example: since = 3
+pub(crate) struct VariantSinceAttribute {
+    pub(crate) keyword: kw::since,
+    pub(crate) version: syn::LitInt,
+}
+
+impl Parse for VariantSinceAttribute {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let keyword = input.parse()?;
+        input.parse::<syn::Token![=]>()?;
+        Ok(Self {
+            keyword,
+            version: input.parse()?,
+        })
+    }
+}
+
+// Example: until = 5
+pub(crate) struct VariantUntilAttribute {
+    pub(crate) keyword: kw::until,
+    pub(crate) version: syn::LitInt,
+}
+
+impl Parse for VariantUntilAttribute {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let keyword = input.parse()?;
+        input.parse::<syn::Token![=]>()?;
+        Ok(Self {
+            keyword,
+            version: input.parse()?,
+        })
+    }
+}
+
+// Example: maps_to = WireKind::Legacy
+pub(crate) struct VariantMapsToAttribute {
+    pub(crate) keyword: kw::maps_to,
+    pub(crate) path: syn::Path,
+}
+
+impl Parse for VariantMapsToAttribute {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let keyword = input.parse()?;
+        input.parse::<syn::Token![=]>()?;
+        Ok(Self {
+            keyword,
+            path: input.parse()?,
+        })
+    }
+}
+
 pub(crate) struct VariantAlternativesAttribute {
     _keyword: kw::alternatives,
     _eq_token: syn::Token![=],